@@ -56,4 +56,112 @@ pub enum AcademyError {
     InvalidAmount,
     #[msg("XP reward must be greater than zero")]
     InvalidXpReward,
+    #[msg("Learner daily XP cap exceeded")]
+    DailyXpCapExceeded,
+    #[msg("Token account is not the recipient's XP account")]
+    TokenAccountMismatch,
+    #[msg("Amount exceeds minter's budget for the current window")]
+    MinterWindowBudgetExceeded,
+    #[msg("Amount exceeds minter's lifetime cap")]
+    MinterLifetimeCapExceeded,
+    #[msg("Window budget requires a window length greater than zero")]
+    InvalidMinterWindow,
+    #[msg("Account is not in the expected pre-migration layout")]
+    InvalidMigrationSource,
+    #[msg("Proposed authority must differ from the current one")]
+    InvalidAuthority,
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("Backend signer scopes must be a non-empty subset of the known scopes")]
+    InvalidSignerScopes,
+    #[msg("Backend signer expiry must be zero or in the future")]
+    InvalidSignerExpiry,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Unknown pause flag")]
+    InvalidPauseFlags,
+    #[msg("Missing or malformed Ed25519 attestation")]
+    InvalidAttestation,
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+    #[msg("Lesson batch is empty")]
+    EmptyLessonBatch,
+    #[msg("Course has a per-lesson XP table that must be passed")]
+    LessonXpTableRequired,
+    #[msg("Per-lesson XP table length must equal the course's lesson count")]
+    LessonXpLengthMismatch,
+    #[msg("Too many prerequisites")]
+    TooManyPrerequisites,
+    #[msg("Prerequisites must be distinct, non-default, and cover the minimum count")]
+    InvalidPrerequisites,
+    #[msg("Credential asset is not an academy credential owned by the learner")]
+    InvalidCredentialAsset,
+    #[msg("Completion receipt must be passed exactly when closing a completed enrollment")]
+    InvalidCompletionReceipt,
+    #[msg("Enrollment window is not open")]
+    EnrollmentWindowClosed,
+    #[msg("Course has no seats left")]
+    CourseFull,
+    #[msg("Cohort has no seats left")]
+    CohortFull,
+    #[msg("Completion deadline has passed")]
+    CompletionDeadlinePassed,
+    #[msg("Course requires enrolling through a cohort")]
+    CohortRequired,
+    #[msg("Cohort does not match the course or enrollment")]
+    CohortMismatch,
+    #[msg("Enrollment window or completion deadline is invalid")]
+    InvalidSchedule,
+    #[msg("Enrollment progress has expired; reset the enrollment to continue")]
+    ProgressExpired,
+    #[msg("Lesson map must give each old lesson a distinct new index or mark it removed")]
+    InvalidLessonMap,
+    #[msg("Lesson map does not apply to this enrollment's course version")]
+    LessonMapNotApplicable,
+    #[msg("Enrollment predates the course's lesson layout; migrate it first")]
+    EnrollmentMigrationRequired,
+    #[msg("Remove the per-lesson XP table before restructuring the course")]
+    LessonXpTablePresent,
+    #[msg("Lessons can only be removed through restructure_course once learners have enrolled")]
+    LessonCountShrinkRequiresRestructure,
+    #[msg("Deactivate the course before closing it")]
+    CourseStillActive,
+    #[msg("Course still has open enrollments")]
+    CourseHasOpenEnrollments,
+    #[msg("Course author needs 1 to 8 distinct track IDs")]
+    InvalidAuthorTracks,
+    #[msg("Course author is not assigned to this track")]
+    TrackNotAssigned,
+    #[msg("Only the platform authority can change this course field")]
+    AuthorityRequired,
+    #[msg("Track name, level range or collection is invalid")]
+    InvalidTrack,
+    #[msg("Collection does not match the track's credential collection")]
+    TrackCollectionMismatch,
+    #[msg("Track course list is invalid for this level")]
+    InvalidTrackCourses,
+    #[msg("Course is not listed on its track at its track level")]
+    CourseNotInTrack,
+    #[msg("Credential cannot be upgraded to a lower level")]
+    CredentialDowngrade,
+    #[msg("New credential asset keypair must sign the first issue on a track")]
+    CredentialAssetMustSign,
+    #[msg("Revocation reason must be 1 to 64 bytes")]
+    InvalidRevocationReason,
+    #[msg("Asset has already been revoked")]
+    AlreadyRevoked,
+    #[msg("Enrollments to clear must be [Course, Enrollment] pairs credited to the revoked asset")]
+    InvalidRevocationAccounts,
+    #[msg("Track has no validity period or recertification course")]
+    RecertificationNotConfigured,
+    #[msg("Course is not the track's recertification course")]
+    NotRecertificationCourse,
+    #[msg("Recertification course was completed before the credential was last certified")]
+    RecertificationTooOld,
+    #[msg("Course ID belonged to a closed course and cannot be reused")]
+    CourseIdRetired,
+    #[msg("Completion bonus basis points exceed 10000")]
+    InvalidCompletionBonus,
+    #[msg("Pass exactly one of enrollment or completion receipt")]
+    InvalidCredentialSource,
 }
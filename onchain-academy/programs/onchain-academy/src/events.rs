@@ -6,6 +6,14 @@ pub struct ConfigUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PauseToggled {
+    pub previous: u8,
+    pub paused: u8,
+    pub by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct CourseCreated {
     pub course: Pubkey,
@@ -13,6 +21,8 @@ pub struct CourseCreated {
     pub creator: Pubkey,
     pub track_id: u16,
     pub track_level: u8,
+    /// Owning course author. Default = authority only.
+    pub author: Pubkey,
     pub timestamp: i64,
 }
 
@@ -20,6 +30,109 @@ pub struct CourseCreated {
 pub struct CourseUpdated {
     pub course: Pubkey,
     pub version: u16,
+    /// Fields whose value actually changed, in parameter order
+    pub changes: Vec<CourseChange>,
+    pub timestamp: i64,
+}
+
+/// One Course field edited by `update_course`, with its old and new value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum CourseChange {
+    ContentTxId { old: [u8; 32], new: [u8; 32] },
+    IsActive { old: bool, new: bool },
+    XpPerLesson { old: u32, new: u32 },
+    CreatorRewardXp { old: u32, new: u32 },
+    MinCompletionsForReward { old: u16, new: u16 },
+    CompletionBonusXp { old: u32, new: u32 },
+    CompletionBonusBps { old: u16, new: u16 },
+    Prerequisites { old: Vec<Pubkey>, new: Vec<Pubkey> },
+    PrerequisiteMinCount { old: u8, new: u8 },
+    EnrollOpensAt { old: i64, new: i64 },
+    EnrollClosesAt { old: i64, new: i64 },
+    CompletionDeadline { old: i64, new: i64 },
+    MaxEnrollments { old: u32, new: u32 },
+    CohortOnly { old: bool, new: bool },
+    ProgressExpiresAfter { old: i64, new: i64 },
+    LessonCount { old: u8, new: u8 },
+    Difficulty { old: u8, new: u8 },
+    TrackId { old: u16, new: u16 },
+    TrackLevel { old: u8, new: u8 },
+    Creator { old: Pubkey, new: Pubkey },
+    Author { old: Pubkey, new: Pubkey },
+}
+
+#[event]
+pub struct CourseClosed {
+    pub course: Pubkey,
+    pub course_id: String,
+    /// Enrollments left open by a forced close
+    pub open_enrollments: u32,
+    pub destination: Pubkey,
+    pub rent_reclaimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseRestructured {
+    pub course: Pubkey,
+    /// Lesson layout version the published LessonMap maps from
+    pub from_version: u16,
+    /// New `course.version` (and lesson layout version)
+    pub to_version: u16,
+    pub old_lesson_count: u8,
+    pub new_lesson_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LessonXpUpdated {
+    pub course: Pubkey,
+    /// Whether a per-lesson table is now in effect
+    pub has_lesson_xp: bool,
+    /// XP for completing every lesson under the new weights
+    pub total_lesson_xp: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CohortCreated {
+    pub cohort: Pubkey,
+    pub course: Pubkey,
+    pub cohort_id: u16,
+    pub enroll_opens_at: i64,
+    pub enroll_closes_at: i64,
+    pub completion_deadline: i64,
+    pub max_enrollments: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CohortUpdated {
+    pub cohort: Pubkey,
+    pub course: Pubkey,
+    pub enroll_opens_at: i64,
+    pub enroll_closes_at: i64,
+    pub completion_deadline: i64,
+    pub max_enrollments: u32,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CohortEnrolled {
+    pub learner: Pubkey,
+    pub course: Pubkey,
+    pub cohort: Pubkey,
+    /// `cohort.total_enrollments` including this learner
+    pub seat: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CohortCompleted {
+    pub learner: Pubkey,
+    pub course: Pubkey,
+    pub cohort: Pubkey,
     pub timestamp: i64,
 }
 
@@ -44,7 +157,7 @@ pub struct LessonCompleted {
 pub struct CourseFinalized {
     pub learner: Pubkey,
     pub course: Pubkey,
-    pub total_xp: u32,
+    pub total_xp: u64,
     pub bonus_xp: u64,
     pub creator: Pubkey,
     pub creator_xp: u32,
@@ -60,12 +173,58 @@ pub struct EnrollmentClosed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct EnrollmentReset {
+    pub learner: Pubkey,
+    pub course: Pubkey,
+    /// Attempt number after the reset
+    pub attempt: u16,
+    pub kept_progress: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EnrollmentVersionMigrated {
+    pub learner: Pubkey,
+    pub course: Pubkey,
+    pub from_version: u16,
+    pub to_version: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrackCreated {
+    pub track_id: u16,
+    pub collection: Pubkey,
+    pub name: String,
+    pub max_level: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrackUpdated {
+    pub track_id: u16,
+    pub collection: Pubkey,
+    pub max_level: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrackCoursesSet {
+    pub track_id: u16,
+    pub level: u8,
+    pub courses: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct CredentialIssued {
     pub learner: Pubkey,
     pub track_id: u16,
     pub credential_asset: Pubkey,
     pub current_level: u8,
+    /// 0 = never expires
+    pub expires_at: i64,
     pub timestamp: i64,
 }
 
@@ -78,11 +237,64 @@ pub struct CredentialUpgraded {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct CredentialRenewed {
+    pub learner: Pubkey,
+    pub track_id: u16,
+    pub credential_asset: Pubkey,
+    pub recertification_course: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialRevoked {
+    pub learner: Pubkey,
+    pub track_id: u16,
+    pub credential_asset: Pubkey,
+    pub reason: String,
+    /// Enrollments whose `credential_asset` was cleared
+    pub enrollments_cleared: u8,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct MinterRegistered {
     pub minter: Pubkey,
     pub label: String,
     pub max_xp_per_call: u64,
+    pub window_seconds: i64,
+    pub max_xp_per_window: u64,
+    pub max_total_xp: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BackendSignerAdded {
+    pub signer: Pubkey,
+    pub label: String,
+    pub scopes: u8,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BackendSignerRemoved {
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseAuthorAdded {
+    pub author: Pubkey,
+    pub label: String,
+    pub track_ids: Vec<u16>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseAuthorRemoved {
+    pub author: Pubkey,
     pub timestamp: i64,
 }
 
@@ -111,6 +323,15 @@ pub struct AchievementAwarded {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AchievementRevoked {
+    pub achievement_id: String,
+    pub recipient: Pubkey,
+    pub asset: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AchievementTypeCreated {
     pub achievement_id: String,
@@ -126,3 +347,14 @@ pub struct AchievementTypeDeactivated {
     pub achievement_id: String,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    /// "config", "course", "enrollment", "minter_role" or "achievement_type"
+    pub account_type: String,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub new_size: u32,
+    pub timestamp: i64,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::ConfigUpdated;
+use crate::state::Config;
+
+/// Second step of an authority transfer, signed by the proposed authority.
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.authority = config.pending_authority;
+    config.pending_authority = Pubkey::default();
+
+    emit!(ConfigUpdated {
+        field: "authority".to_string(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.pending_authority != Pubkey::default() @ AcademyError::NoPendingAuthority,
+        constraint = config.pending_authority == new_authority.key() @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub new_authority: Signer<'info>,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::BackendSignerAdded;
+use crate::state::{minter_role::MAX_LABEL_LEN, BackendSigner, Config};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddBackendSignerParams {
+    pub signer: Pubkey,
+    pub label: String,
+    /// Bitmask of `BackendSigner::SCOPE_*` flags
+    pub scopes: u8,
+    /// Unix timestamp from which the signer is rejected. 0 = never expires.
+    pub expires_at: i64,
+}
+
+pub fn handler(ctx: Context<AddBackendSigner>, params: AddBackendSignerParams) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        params.label.len() <= MAX_LABEL_LEN,
+        AcademyError::LabelTooLong
+    );
+    require!(
+        params.scopes != 0 && params.scopes & !BackendSigner::ALL_SCOPES == 0,
+        AcademyError::InvalidSignerScopes
+    );
+    require!(
+        params.expires_at == 0 || params.expires_at > now,
+        AcademyError::InvalidSignerExpiry
+    );
+
+    let entry = &mut ctx.accounts.backend_signer;
+    entry.schema_version = BackendSigner::SCHEMA_VERSION;
+    entry.signer = params.signer;
+    entry.label = params.label.clone();
+    entry.scopes = params.scopes;
+    entry.expires_at = params.expires_at;
+    entry.created_at = now;
+    entry._reserved = [0u8; 32];
+    entry.bump = ctx.bumps.backend_signer;
+
+    emit!(BackendSignerAdded {
+        signer: params.signer,
+        label: params.label,
+        scopes: params.scopes,
+        expires_at: params.expires_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: AddBackendSignerParams)]
+pub struct AddBackendSigner<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = BackendSigner::SIZE,
+        seeds = [b"backend_signer", params.signer.as_ref()],
+        bump,
+    )]
+    pub backend_signer: Account<'info, BackendSigner>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ AcademyError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::CourseAuthorAdded;
+use crate::state::{minter_role::MAX_LABEL_LEN, Config, CourseAuthor, MAX_AUTHOR_TRACKS};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddCourseAuthorParams {
+    pub author: Pubkey,
+    pub label: String,
+    /// Tracks the author may create courses on, up to `MAX_AUTHOR_TRACKS`
+    pub track_ids: Vec<u16>,
+}
+
+pub fn handler(ctx: Context<AddCourseAuthor>, params: AddCourseAuthorParams) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        params.label.len() <= MAX_LABEL_LEN,
+        AcademyError::LabelTooLong
+    );
+    require!(
+        !params.track_ids.is_empty() && params.track_ids.len() <= MAX_AUTHOR_TRACKS,
+        AcademyError::InvalidAuthorTracks
+    );
+    for (i, track_id) in params.track_ids.iter().enumerate() {
+        require!(
+            !params.track_ids[..i].contains(track_id),
+            AcademyError::InvalidAuthorTracks
+        );
+    }
+
+    let entry = &mut ctx.accounts.course_author;
+    entry.schema_version = CourseAuthor::SCHEMA_VERSION;
+    entry.author = params.author;
+    entry.label = params.label.clone();
+    entry.track_ids = params.track_ids.clone();
+    entry.created_at = now;
+    entry._reserved = [0u8; 32];
+    entry.bump = ctx.bumps.course_author;
+
+    emit!(CourseAuthorAdded {
+        author: params.author,
+        label: params.label,
+        track_ids: params.track_ids,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: AddCourseAuthorParams)]
+pub struct AddCourseAuthor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CourseAuthor::SIZE,
+        seeds = [b"course_author", params.author.as_ref()],
+        bump,
+    )]
+    pub course_author: Account<'info, CourseAuthor>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ AcademyError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
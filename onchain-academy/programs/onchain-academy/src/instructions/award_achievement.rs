@@ -9,27 +9,51 @@ use mpl_core::{
 
 use crate::errors::AcademyError;
 use crate::events::AchievementAwarded;
-use crate::state::{AchievementReceipt, AchievementType, Config, MinterRole};
-use crate::utils::mint_xp;
+use crate::state::{AchievementReceipt, AchievementType, Config, LearnerProfile, MinterRole};
+use crate::utils::{mint_xp, require_xp_token_account};
 
 pub fn handler(ctx: Context<AwardAchievement>) -> Result<()> {
+    let mut pause_flags = Config::PAUSE_ACHIEVEMENTS;
+    if ctx.accounts.achievement_type.xp_reward > 0 {
+        pause_flags |= Config::PAUSE_XP;
+    }
+    ctx.accounts.config.require_not_paused(pause_flags)?;
+
     let achievement = &ctx.accounts.achievement_type;
     let role = &ctx.accounts.minter_role;
 
     require!(achievement.is_active, AcademyError::AchievementNotActive);
     require!(role.is_active, AcademyError::MinterNotActive);
 
-    if achievement.max_supply > 0 {
-        require!(
-            achievement.current_supply < achievement.max_supply,
-            AcademyError::AchievementSupplyExhausted
-        );
-    }
+    require!(
+        achievement.has_supply(),
+        AcademyError::AchievementSupplyExhausted
+    );
 
     let config = &ctx.accounts.config;
     let config_seeds: &[&[u8]] = &[b"config", &[config.bump]];
     let signer_seeds = &[config_seeds];
 
+    let now = Clock::get()?.unix_timestamp;
+
+    let profile = &mut ctx.accounts.recipient_profile;
+    profile.init_if_new(
+        ctx.accounts.recipient.key(),
+        now,
+        ctx.bumps.recipient_profile,
+    );
+    if achievement.xp_reward > 0 {
+        require_xp_token_account(
+            &ctx.accounts.recipient_token_account,
+            &config.xp_mint,
+            &ctx.accounts.recipient.key(),
+        )?;
+        profile.record_xp(achievement.xp_reward as u64, now, config.daily_xp_cap)?;
+        ctx.accounts
+            .minter_role
+            .record_mint(achievement.xp_reward as u64, now)?;
+    }
+
     let next_supply = achievement
         .current_supply
         .checked_add(1)
@@ -80,21 +104,10 @@ pub fn handler(ctx: Context<AwardAchievement>) -> Result<()> {
         )?;
     }
 
-    let now = Clock::get()?.unix_timestamp;
-
     // Update achievement supply
     let achievement_mut = &mut ctx.accounts.achievement_type;
     achievement_mut.current_supply = next_supply;
 
-    // Update minter stats
-    let role_mut = &mut ctx.accounts.minter_role;
-    if achievement_mut.xp_reward > 0 {
-        role_mut.total_xp_minted = role_mut
-            .total_xp_minted
-            .checked_add(achievement_mut.xp_reward as u64)
-            .ok_or(AcademyError::Overflow)?;
-    }
-
     // Initialize receipt
     let receipt = &mut ctx.accounts.achievement_receipt;
     receipt.asset = ctx.accounts.asset.key();
@@ -162,7 +175,17 @@ pub struct AwardAchievement<'info> {
     /// CHECK: Recipient of the achievement NFT.
     pub recipient: AccountInfo<'info>,
 
-    /// CHECK: Recipient's Token-2022 ATA. Only used if xp_reward > 0. Validated by CPI.
+    /// Created here if the recipient has never enrolled
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LearnerProfile::SIZE,
+        seeds = [b"learner", recipient.key().as_ref()],
+        bump,
+    )]
+    pub recipient_profile: Account<'info, LearnerProfile>,
+
+    /// CHECK: Recipient's Token-2022 ATA. Only used if xp_reward > 0. Mint and owner validated in handler.
     #[account(mut)]
     pub recipient_token_account: AccountInfo<'info>,
 
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::ConfigUpdated;
+use crate::state::Config;
+
+/// Withdraws a pending authority proposal before it is accepted.
+pub fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.pending_authority = Pubkey::default();
+
+    emit!(ConfigUpdated {
+        field: "pending_authority".to_string(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+        constraint = config.pending_authority != Pubkey::default() @ AcademyError::NoPendingAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::CourseClosed;
+use crate::state::{Config, Course, CourseTombstone};
+
+/// Closes a deactivated course and sends its rent to `destination`. Unless
+/// `force` is set, every enrollment must have been closed first; learners
+/// reclaim enrollments left behind by a forced close with
+/// `close_orphaned_enrollment`. A tombstone keeps the course ID from being
+/// reused, so stale enrollments and receipts never attach to a new course.
+pub fn handler(ctx: Context<CloseCourse>, force: bool) -> Result<()> {
+    let course = &ctx.accounts.course;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!course.is_active, AcademyError::CourseStillActive);
+    require!(!course.has_lesson_xp, AcademyError::LessonXpTablePresent);
+    require!(
+        force || course.open_enrollments == 0,
+        AcademyError::CourseHasOpenEnrollments
+    );
+
+    let tombstone = &mut ctx.accounts.course_tombstone;
+    tombstone.schema_version = CourseTombstone::SCHEMA_VERSION;
+    tombstone.course = course.key();
+    tombstone.closed_at = now;
+    tombstone._reserved = [0u8; 8];
+    tombstone.bump = ctx.bumps.course_tombstone;
+
+    emit!(CourseClosed {
+        course: course.key(),
+        course_id: course.course_id.clone(),
+        open_enrollments: course.open_enrollments,
+        destination: ctx.accounts.destination.key(),
+        rent_reclaimed: course.to_account_info().lamports(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseCourse<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = destination,
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CourseTombstone::SIZE,
+        seeds = [b"course_tombstone", course.course_id.as_bytes()],
+        bump,
+    )]
+    pub course_tombstone: Account<'info, CourseTombstone>,
+
+    /// CHECK: Any account chosen by the authority to receive the rent.
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+
+    /// Pays for the tombstone
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -2,9 +2,11 @@ use anchor_lang::prelude::*;
 
 use crate::errors::AcademyError;
 use crate::events::EnrollmentClosed;
-use crate::state::{Course, Enrollment};
+use crate::state::{CompletionReceipt, Config, Course, Enrollment};
 
 pub fn handler(ctx: Context<CloseEnrollment>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_ALL)?;
+
     let enrollment = &ctx.accounts.enrollment;
     let now = Clock::get()?.unix_timestamp;
 
@@ -15,6 +17,28 @@ pub fn handler(ctx: Context<CloseEnrollment>) -> Result<()> {
         require!(elapsed > 86400, AcademyError::UnenrollCooldown);
     }
 
+    // Completed enrollments leave a CompletionReceipt behind so prerequisite
+    // checks keep working after the enrollment rent is reclaimed.
+    match (
+        enrollment.completed_at,
+        ctx.accounts.completion_receipt.as_mut(),
+    ) {
+        (Some(completed_at), Some(receipt)) => {
+            receipt.schema_version = CompletionReceipt::SCHEMA_VERSION;
+            receipt.course = enrollment.course;
+            receipt.completed_at = completed_at;
+            receipt.credential_asset = enrollment.credential_asset;
+            receipt.course_version = ctx.accounts.course.version;
+            receipt._reserved = [0u8; 8];
+            receipt.bump = ctx.bumps.completion_receipt.unwrap_or_default();
+        }
+        (None, None) => {}
+        _ => return err!(AcademyError::InvalidCompletionReceipt),
+    }
+
+    let course = &mut ctx.accounts.course;
+    course.open_enrollments = course.open_enrollments.saturating_sub(1);
+
     let rent_reclaimed = ctx.accounts.enrollment.to_account_info().lamports();
 
     emit!(EnrollmentClosed {
@@ -32,6 +56,13 @@ pub fn handler(ctx: Context<CloseEnrollment>) -> Result<()> {
 #[derive(Accounts)]
 pub struct CloseEnrollment<'info> {
     #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
         seeds = [b"course", course.course_id.as_bytes()],
         bump = course.bump,
     )]
@@ -46,6 +77,19 @@ pub struct CloseEnrollment<'info> {
     )]
     pub enrollment: Account<'info, Enrollment>,
 
+    /// Required when the enrollment is completed; must be omitted otherwise.
+    /// Re-completing a course overwrites the previous receipt.
+    #[account(
+        init_if_needed,
+        payer = learner,
+        space = CompletionReceipt::SIZE,
+        seeds = [b"completion", course.key().as_ref(), learner.key().as_ref()],
+        bump,
+    )]
+    pub completion_receipt: Option<Account<'info, CompletionReceipt>>,
+
     #[account(mut)]
     pub learner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::EnrollmentClosed;
+use crate::state::{Config, CourseTombstone, Enrollment};
+
+/// Returns the rent of an enrollment whose course was force-closed. The
+/// course's tombstone proves it is gone; no completion receipt is written,
+/// since a closed course can no longer be named as a prerequisite.
+pub fn handler(ctx: Context<CloseOrphanedEnrollment>, _course_id: String) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_ALL)?;
+
+    let enrollment = &ctx.accounts.enrollment;
+
+    emit!(EnrollmentClosed {
+        learner: ctx.accounts.learner.key(),
+        course: enrollment.course,
+        completed: enrollment.completed_at.is_some(),
+        rent_reclaimed: enrollment.to_account_info().lamports(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(course_id: String)]
+pub struct CloseOrphanedEnrollment<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"course_tombstone", course_id.as_bytes()],
+        bump = course_tombstone.bump,
+    )]
+    pub course_tombstone: Account<'info, CourseTombstone>,
+
+    #[account(
+        mut,
+        seeds = [b"enrollment", course_id.as_bytes(), learner.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.course == course_tombstone.course @ AcademyError::EnrollmentCourseMismatch,
+        close = learner,
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+
+    #[account(mut)]
+    pub learner: Signer<'info>,
+}
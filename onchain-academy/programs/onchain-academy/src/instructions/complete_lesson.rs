@@ -2,18 +2,30 @@ use anchor_lang::prelude::*;
 
 use crate::errors::AcademyError;
 use crate::events::LessonCompleted;
-use crate::state::{Config, Course, Enrollment};
+use crate::state::{BackendSigner, Config, Course, CourseLessonXp, Enrollment, LearnerProfile};
 use crate::utils;
 
 pub fn handler(ctx: Context<CompleteLesson>, lesson_index: u8) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_XP)?;
+
     let course = &ctx.accounts.course;
     let enrollment = &mut ctx.accounts.enrollment;
     let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
 
+    require!(
+        !enrollment.progress_expired(course.progress_expires_after, now),
+        AcademyError::ProgressExpired
+    );
+    require!(
+        !enrollment.needs_lesson_migration(course.lesson_layout_version),
+        AcademyError::EnrollmentMigrationRequired
+    );
     require!(
         lesson_index < course.lesson_count,
         AcademyError::LessonOutOfBounds
     );
+    let xp_earned = course.lesson_xp(lesson_index, ctx.accounts.lesson_xp.as_deref())?;
 
     let word_index = (lesson_index / 64) as usize;
     let bit_index = lesson_index % 64;
@@ -25,6 +37,16 @@ pub fn handler(ctx: Context<CompleteLesson>, lesson_index: u8) -> Result<()> {
     );
     enrollment.lesson_flags[word_index] |= mask;
 
+    utils::require_xp_token_account(
+        &ctx.accounts.learner_token_account,
+        &config.xp_mint,
+        &ctx.accounts.learner.key(),
+    )?;
+
+    let profile = &mut ctx.accounts.learner_profile;
+    profile.record_xp(xp_earned as u64, now, config.daily_xp_cap)?;
+    profile.record_activity(now);
+
     let config_seeds: &[&[u8]] = &[b"config", &[config.bump]];
 
     utils::mint_xp(
@@ -33,15 +55,15 @@ pub fn handler(ctx: Context<CompleteLesson>, lesson_index: u8) -> Result<()> {
         &ctx.accounts.config.to_account_info(),
         &ctx.accounts.token_program.to_account_info(),
         config_seeds,
-        course.xp_per_lesson as u64,
+        xp_earned as u64,
     )?;
 
     emit!(LessonCompleted {
         learner: ctx.accounts.learner.key(),
         course: course.key(),
         lesson_index,
-        xp_earned: course.xp_per_lesson,
-        timestamp: Clock::get()?.unix_timestamp,
+        xp_earned,
+        timestamp: now,
     });
 
     Ok(())
@@ -61,6 +83,13 @@ pub struct CompleteLesson<'info> {
     )]
     pub course: Account<'info, Course>,
 
+    /// Per-lesson XP table. Required when `course.has_lesson_xp`.
+    #[account(
+        seeds = [b"lesson_xp", course.key().as_ref()],
+        bump = lesson_xp.bump,
+    )]
+    pub lesson_xp: Option<Account<'info, CourseLessonXp>>,
+
     #[account(
         mut,
         seeds = [b"enrollment", course.course_id.as_bytes(), learner.key().as_ref()],
@@ -69,6 +98,13 @@ pub struct CompleteLesson<'info> {
     )]
     pub enrollment: Account<'info, Enrollment>,
 
+    #[account(
+        mut,
+        seeds = [b"learner", learner.key().as_ref()],
+        bump = learner_profile.bump,
+    )]
+    pub learner_profile: Account<'info, LearnerProfile>,
+
     /// CHECK: Tied to enrollment PDA via seeds constraint.
     pub learner: AccountInfo<'info>,
 
@@ -87,10 +123,22 @@ pub struct CompleteLesson<'info> {
     pub xp_mint: AccountInfo<'info>,
 
     #[account(
-        constraint = backend_signer.key() == config.backend_signer @ AcademyError::Unauthorized,
+        constraint = utils::is_backend_signer(
+            &config,
+            backend_signer_role.as_deref(),
+            &backend_signer.key(),
+            BackendSigner::SCOPE_LESSONS,
+        )? @ AcademyError::Unauthorized,
     )]
     pub backend_signer: Signer<'info>,
 
+    /// Registry entry for `backend_signer`. Omit when signing as `config.backend_signer`.
+    #[account(
+        seeds = [b"backend_signer", backend_signer.key().as_ref()],
+        bump = backend_signer_role.bump,
+    )]
+    pub backend_signer_role: Option<Account<'info, BackendSigner>>,
+
     /// CHECK: Validated by address constraint.
     #[account(address = spl_token_2022::id())]
     pub token_program: AccountInfo<'info>,
@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+use crate::errors::AcademyError;
+use crate::events::LessonCompleted;
+use crate::state::{BackendSigner, Config, Course, CourseLessonXp, Enrollment, LearnerProfile};
+use crate::utils;
+
+/// Length of the message a backend signer attests to; see `lesson_attestation_message`.
+pub const LESSON_ATTESTATION_LEN: usize = 32 + 32 + 1 + 8 + 2 + 2 + 8;
+
+/// Message signed by the backend for `complete_lesson_attested`:
+/// course (32) || learner (32) || lesson_index (1) || enrolled_at (8, LE)
+/// || attempt (2, LE) || course_version (2, LE) || expires_at (8, LE).
+/// The course PDA already binds the message to this program; `enrolled_at`
+/// and `attempt` bind it to one enrollment attempt, so it cannot be replayed
+/// after `reset_enrollment` or a close and re-enroll. `course_version` binds
+/// `lesson_index` to the lesson layout the backend saw, so it cannot land on
+/// a different lesson after `restructure_course`.
+pub fn lesson_attestation_message(
+    course: &Pubkey,
+    learner: &Pubkey,
+    lesson_index: u8,
+    enrolled_at: i64,
+    attempt: u16,
+    course_version: u16,
+    expires_at: i64,
+) -> [u8; LESSON_ATTESTATION_LEN] {
+    let mut message = [0u8; LESSON_ATTESTATION_LEN];
+    message[..32].copy_from_slice(course.as_ref());
+    message[32..64].copy_from_slice(learner.as_ref());
+    message[64] = lesson_index;
+    message[65..73].copy_from_slice(&enrolled_at.to_le_bytes());
+    message[73..75].copy_from_slice(&attempt.to_le_bytes());
+    message[75..77].copy_from_slice(&course_version.to_le_bytes());
+    message[77..].copy_from_slice(&expires_at.to_le_bytes());
+    message
+}
+
+/// Learner-submitted `complete_lesson`. The preceding instruction must be an
+/// Ed25519 precompile verification of `lesson_attestation_message` signed by
+/// a backend signer with `SCOPE_LESSONS`.
+pub fn handler(
+    ctx: Context<CompleteLessonAttested>,
+    lesson_index: u8,
+    expires_at: i64,
+) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_XP)?;
+
+    let course = &ctx.accounts.course;
+    let enrollment = &mut ctx.accounts.enrollment;
+    let config = &ctx.accounts.config;
+    let learner = ctx.accounts.learner.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now < expires_at, AcademyError::AttestationExpired);
+    require!(
+        !enrollment.progress_expired(course.progress_expires_after, now),
+        AcademyError::ProgressExpired
+    );
+    require!(
+        !enrollment.needs_lesson_migration(course.lesson_layout_version),
+        AcademyError::EnrollmentMigrationRequired
+    );
+
+    let (attester, message) = utils::load_ed25519_attestation(&ctx.accounts.instructions)?;
+    require!(
+        utils::is_backend_signer(
+            config,
+            ctx.accounts.backend_signer_role.as_deref(),
+            &attester,
+            BackendSigner::SCOPE_LESSONS,
+        )?,
+        AcademyError::Unauthorized
+    );
+    require!(
+        message
+            == lesson_attestation_message(
+                &course.key(),
+                &learner,
+                lesson_index,
+                enrollment.enrolled_at,
+                enrollment.attempt,
+                enrollment.course_version,
+                expires_at,
+            ),
+        AcademyError::InvalidAttestation
+    );
+
+    require!(
+        lesson_index < course.lesson_count,
+        AcademyError::LessonOutOfBounds
+    );
+    let xp_earned = course.lesson_xp(lesson_index, ctx.accounts.lesson_xp.as_deref())?;
+
+    let word_index = (lesson_index / 64) as usize;
+    let bit_index = lesson_index % 64;
+    let mask = 1u64 << bit_index;
+
+    require!(
+        enrollment.lesson_flags[word_index] & mask == 0,
+        AcademyError::LessonAlreadyCompleted
+    );
+    enrollment.lesson_flags[word_index] |= mask;
+
+    utils::require_xp_token_account(
+        &ctx.accounts.learner_token_account,
+        &config.xp_mint,
+        &learner,
+    )?;
+
+    let profile = &mut ctx.accounts.learner_profile;
+    profile.record_xp(xp_earned as u64, now, config.daily_xp_cap)?;
+    profile.record_activity(now);
+
+    let config_seeds: &[&[u8]] = &[b"config", &[config.bump]];
+
+    utils::mint_xp(
+        &ctx.accounts.xp_mint.to_account_info(),
+        &ctx.accounts.learner_token_account.to_account_info(),
+        &ctx.accounts.config.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        config_seeds,
+        xp_earned as u64,
+    )?;
+
+    emit!(LessonCompleted {
+        learner,
+        course: course.key(),
+        lesson_index,
+        xp_earned,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompleteLessonAttested<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    /// Per-lesson XP table. Required when `course.has_lesson_xp`.
+    #[account(
+        seeds = [b"lesson_xp", course.key().as_ref()],
+        bump = lesson_xp.bump,
+    )]
+    pub lesson_xp: Option<Account<'info, CourseLessonXp>>,
+
+    #[account(
+        mut,
+        seeds = [b"enrollment", course.course_id.as_bytes(), learner.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.course == course.key() @ AcademyError::EnrollmentCourseMismatch,
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+
+    #[account(
+        mut,
+        seeds = [b"learner", learner.key().as_ref()],
+        bump = learner_profile.bump,
+    )]
+    pub learner_profile: Account<'info, LearnerProfile>,
+
+    pub learner: Signer<'info>,
+
+    /// CHECK: Token-2022 ATA for learner's XP. Validated by Token-2022 CPI + program owner check.
+    #[account(
+        mut,
+        constraint = learner_token_account.owner == &spl_token_2022::id() @ AcademyError::Unauthorized,
+    )]
+    pub learner_token_account: AccountInfo<'info>,
+
+    /// CHECK: XP mint. Validated by Config.xp_mint constraint.
+    #[account(
+        mut,
+        constraint = xp_mint.key() == config.xp_mint @ AcademyError::Unauthorized,
+    )]
+    pub xp_mint: AccountInfo<'info>,
+
+    /// Registry entry for the attesting key. Omit when `config.backend_signer` attested.
+    #[account(
+        seeds = [b"backend_signer", backend_signer_role.signer.as_ref()],
+        bump = backend_signer_role.bump,
+    )]
+    pub backend_signer_role: Option<Account<'info, BackendSigner>>,
+
+    /// CHECK: Validated by address constraint.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// CHECK: Validated by address constraint.
+    #[account(address = spl_token_2022::id())]
+    pub token_program: AccountInfo<'info>,
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::LessonCompleted;
+use crate::instructions::complete_lesson::CompleteLesson;
+use crate::state::Config;
+use crate::utils;
+
+/// Batch form of `complete_lesson`: sets every bit in `lesson_indices`, then
+/// credits and mints the summed XP with a single Token-2022 CPI. Fails as a
+/// whole if any index is out of range, repeated, or already completed.
+pub fn handler(ctx: Context<CompleteLesson>, lesson_indices: Vec<u8>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_XP)?;
+
+    let course = &ctx.accounts.course;
+    let enrollment = &mut ctx.accounts.enrollment;
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!lesson_indices.is_empty(), AcademyError::EmptyLessonBatch);
+    require!(
+        !enrollment.progress_expired(course.progress_expires_after, now),
+        AcademyError::ProgressExpired
+    );
+    require!(
+        !enrollment.needs_lesson_migration(course.lesson_layout_version),
+        AcademyError::EnrollmentMigrationRequired
+    );
+
+    let lesson_xp_table = ctx.accounts.lesson_xp.as_deref();
+    let mut lesson_xp = Vec::with_capacity(lesson_indices.len());
+    let mut total_xp: u64 = 0;
+
+    for &lesson_index in &lesson_indices {
+        require!(
+            lesson_index < course.lesson_count,
+            AcademyError::LessonOutOfBounds
+        );
+        let xp_earned = course.lesson_xp(lesson_index, lesson_xp_table)?;
+        total_xp = total_xp
+            .checked_add(xp_earned as u64)
+            .ok_or(AcademyError::Overflow)?;
+        lesson_xp.push(xp_earned);
+
+        let word_index = (lesson_index / 64) as usize;
+        let bit_index = lesson_index % 64;
+        let mask = 1u64 << bit_index;
+
+        // Also catches duplicates within the batch
+        require!(
+            enrollment.lesson_flags[word_index] & mask == 0,
+            AcademyError::LessonAlreadyCompleted
+        );
+        enrollment.lesson_flags[word_index] |= mask;
+    }
+
+    utils::require_xp_token_account(
+        &ctx.accounts.learner_token_account,
+        &config.xp_mint,
+        &ctx.accounts.learner.key(),
+    )?;
+
+    let profile = &mut ctx.accounts.learner_profile;
+    profile.record_xp(total_xp, now, config.daily_xp_cap)?;
+    profile.record_activity(now);
+
+    let config_seeds: &[&[u8]] = &[b"config", &[config.bump]];
+
+    utils::mint_xp(
+        &ctx.accounts.xp_mint.to_account_info(),
+        &ctx.accounts.learner_token_account.to_account_info(),
+        &ctx.accounts.config.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        config_seeds,
+        total_xp,
+    )?;
+
+    for (lesson_index, xp_earned) in lesson_indices.into_iter().zip(lesson_xp) {
+        emit!(LessonCompleted {
+            learner: ctx.accounts.learner.key(),
+            course: course.key(),
+            lesson_index,
+            xp_earned,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
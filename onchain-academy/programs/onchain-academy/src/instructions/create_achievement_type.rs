@@ -51,6 +51,7 @@ pub fn handler(
         .invoke_signed(signer_seeds)?;
 
     let achievement = &mut ctx.accounts.achievement_type;
+    achievement.schema_version = AchievementType::SCHEMA_VERSION;
     achievement.achievement_id = params.achievement_id;
     achievement.name = params.name;
     achievement.metadata_uri = params.metadata_uri;
@@ -61,7 +62,8 @@ pub fn handler(
     achievement.xp_reward = params.xp_reward;
     achievement.is_active = true;
     achievement.created_at = Clock::get()?.unix_timestamp;
-    achievement._reserved = [0u8; 8];
+    achievement.revoked_count = 0;
+    achievement._reserved = [0u8; 28];
     achievement.bump = ctx.bumps.achievement_type;
 
     emit!(AchievementTypeCreated {
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::CohortCreated;
+use crate::state::{validate_schedule, Cohort, Config, Course};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateCohortParams {
+    pub cohort_id: u16,
+    /// Enrollment window; 0 leaves either end open
+    pub enroll_opens_at: i64,
+    pub enroll_closes_at: i64,
+    /// Last moment `finalize_course` succeeds for this cohort. 0 = no deadline.
+    pub completion_deadline: i64,
+    /// 0 = unlimited
+    pub max_enrollments: u32,
+}
+
+pub fn handler(ctx: Context<CreateCohort>, params: CreateCohortParams) -> Result<()> {
+    validate_schedule(
+        params.enroll_opens_at,
+        params.enroll_closes_at,
+        params.completion_deadline,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let cohort = &mut ctx.accounts.cohort;
+
+    cohort.schema_version = Cohort::SCHEMA_VERSION;
+    cohort.course = ctx.accounts.course.key();
+    cohort.cohort_id = params.cohort_id;
+    cohort.enroll_opens_at = params.enroll_opens_at;
+    cohort.enroll_closes_at = params.enroll_closes_at;
+    cohort.completion_deadline = params.completion_deadline;
+    cohort.max_enrollments = params.max_enrollments;
+    cohort.total_enrollments = 0;
+    cohort.total_completions = 0;
+    cohort.is_active = true;
+    cohort.created_at = now;
+    cohort._reserved = [0u8; 32];
+    cohort.bump = ctx.bumps.cohort;
+
+    emit!(CohortCreated {
+        cohort: cohort.key(),
+        course: cohort.course,
+        cohort_id: params.cohort_id,
+        enroll_opens_at: params.enroll_opens_at,
+        enroll_closes_at: params.enroll_closes_at,
+        completion_deadline: params.completion_deadline,
+        max_enrollments: params.max_enrollments,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateCohortParams)]
+pub struct CreateCohort<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Cohort::SIZE,
+        seeds = [b"cohort", course.key().as_ref(), &params.cohort_id.to_le_bytes()],
+        bump,
+    )]
+    pub cohort: Account<'info, Cohort>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
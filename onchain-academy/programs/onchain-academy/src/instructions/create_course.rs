@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 
 use crate::errors::AcademyError;
 use crate::events::CourseCreated;
-use crate::state::{Config, Course, MAX_COURSE_ID_LEN};
+use crate::state::{
+    validate_schedule, Config, Course, CourseAuthor, MAX_BASIS_POINTS, MAX_COURSE_ID_LEN,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateCourseParams {
@@ -14,11 +16,32 @@ pub struct CreateCourseParams {
     pub xp_per_lesson: u32,
     pub track_id: u16,
     pub track_level: u8,
-    pub prerequisite: Option<Pubkey>,
+    /// Up to `MAX_PREREQUISITES` course PDAs
+    pub prerequisites: Vec<Pubkey>,
+    /// Prerequisites required to enroll: 0 = all (AND), 1 = any (OR), k = k of n
+    pub prerequisite_min_count: u8,
     pub creator_reward_xp: u32,
     pub min_completions_for_reward: u16,
+    /// XP minted to the learner on `finalize_course`
+    pub completion_bonus_xp: u32,
+    /// Added to `completion_bonus_xp`: basis points of the total lesson XP
+    pub completion_bonus_bps: u16,
+    /// Enrollment window; 0 leaves either end open
+    pub enroll_opens_at: i64,
+    pub enroll_closes_at: i64,
+    /// Last moment `finalize_course` succeeds. 0 = no deadline.
+    pub completion_deadline: i64,
+    /// 0 = unlimited
+    pub max_enrollments: u32,
+    /// Only allow enrolling through a `Cohort`
+    pub cohort_only: bool,
+    /// Seconds before an attempt's progress goes stale. 0 = never.
+    pub progress_expires_after: i64,
 }
 
+/// Signed by the authority, or by a course author on one of its tracks; the
+/// author then owns the course (see `Course.author`). Authors cannot set up
+/// a creator reward and are blocked by `PAUSE_ALL`.
 pub fn handler(ctx: Context<CreateCourse>, params: CreateCourseParams) -> Result<()> {
     require!(!params.course_id.is_empty(), AcademyError::CourseIdEmpty);
     require!(
@@ -30,10 +53,48 @@ pub fn handler(ctx: Context<CreateCourse>, params: CreateCourseParams) -> Result
         (1..=3).contains(&params.difficulty),
         AcademyError::InvalidDifficulty
     );
+    validate_schedule(
+        params.enroll_opens_at,
+        params.enroll_closes_at,
+        params.completion_deadline,
+    )?;
+    require!(
+        params.progress_expires_after >= 0,
+        AcademyError::InvalidSchedule
+    );
+    require!(
+        params.completion_bonus_bps <= MAX_BASIS_POINTS,
+        AcademyError::InvalidCompletionBonus
+    );
+
+    let course_key = ctx.accounts.course.key();
+    require!(
+        !params.prerequisites.contains(&course_key),
+        AcademyError::InvalidPrerequisites
+    );
+
+    let author = match ctx.accounts.course_author.as_deref() {
+        Some(course_author) if ctx.accounts.authority.key() != ctx.accounts.config.authority => {
+            ctx.accounts.config.require_not_paused(Config::PAUSE_ALL)?;
+            require!(
+                course_author.allows_track(params.track_id),
+                AcademyError::TrackNotAssigned
+            );
+            // Creator rewards mint XP to a wallet of the signer's choosing;
+            // only the authority may set them up
+            require!(
+                params.creator_reward_xp == 0 && params.min_completions_for_reward == 0,
+                AcademyError::AuthorityRequired
+            );
+            course_author.author
+        }
+        _ => Pubkey::default(),
+    };
 
     let now = Clock::get()?.unix_timestamp;
     let course = &mut ctx.accounts.course;
 
+    course.schema_version = Course::SCHEMA_VERSION;
     course.course_id = params.course_id.clone();
     course.creator = params.creator;
     course.content_tx_id = params.content_tx_id;
@@ -43,7 +104,7 @@ pub fn handler(ctx: Context<CreateCourse>, params: CreateCourseParams) -> Result
     course.xp_per_lesson = params.xp_per_lesson;
     course.track_id = params.track_id;
     course.track_level = params.track_level;
-    course.prerequisite = params.prerequisite;
+    course.set_prerequisites(&params.prerequisites, params.prerequisite_min_count)?;
     course.creator_reward_xp = params.creator_reward_xp;
     course.min_completions_for_reward = params.min_completions_for_reward;
     course.total_completions = 0;
@@ -51,15 +112,28 @@ pub fn handler(ctx: Context<CreateCourse>, params: CreateCourseParams) -> Result
     course.is_active = true;
     course.created_at = now;
     course.updated_at = now;
-    course._reserved = [0u8; 8];
+    course.completion_bonus_xp = params.completion_bonus_xp;
+    course.has_lesson_xp = false;
+    course.enroll_opens_at = params.enroll_opens_at;
+    course.enroll_closes_at = params.enroll_closes_at;
+    course.completion_deadline = params.completion_deadline;
+    course.max_enrollments = params.max_enrollments;
+    course.cohort_only = params.cohort_only;
+    course.progress_expires_after = params.progress_expires_after;
+    course.lesson_layout_version = 0;
+    course.open_enrollments = 0;
+    course.author = author;
+    course.completion_bonus_bps = params.completion_bonus_bps;
+    course._reserved = [0u8; 77];
     course.bump = ctx.bumps.course;
 
     emit!(CourseCreated {
-        course: ctx.accounts.course.key(),
+        course: course_key,
         course_id: params.course_id,
         creator: params.creator,
         track_id: params.track_id,
         track_level: params.track_level,
+        author,
         timestamp: now,
     });
 
@@ -81,12 +155,31 @@ pub struct CreateCourse<'info> {
     #[account(
         seeds = [b"config"],
         bump = config.bump,
-        has_one = authority @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
 
-    #[account(mut)]
+    /// `config.authority`, or a registered course author
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority
+            || course_author.is_some() @ AcademyError::Unauthorized,
+    )]
     pub authority: Signer<'info>,
 
+    /// Registry entry for `authority` when signing as a course author
+    #[account(
+        seeds = [b"course_author", authority.key().as_ref()],
+        bump = course_author.bump,
+    )]
+    pub course_author: Option<Account<'info, CourseAuthor>>,
+
+    /// CHECK: Must be empty; `close_course` leaves a tombstone here.
+    #[account(
+        seeds = [b"course_tombstone", params.course_id.as_bytes()],
+        bump,
+        constraint = course_tombstone.data_is_empty() @ AcademyError::CourseIdRetired,
+    )]
+    pub course_tombstone: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
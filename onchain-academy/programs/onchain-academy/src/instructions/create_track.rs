@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::TrackCreated;
+use crate::state::{Config, Track, MAX_TRACK_LEVELS, MAX_TRACK_NAME_LEN, MAX_TRACK_URI_LEN};
+use crate::utils;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateTrackParams {
+    pub track_id: u16,
+    pub name: String,
+    /// Credential metadata URI template (see `Track.uri_template`)
+    pub uri_template: String,
+    /// Levels run from 1 to `max_level` (at most `MAX_TRACK_LEVELS`)
+    pub max_level: u8,
+    /// Seconds a credential stays valid after issue or renewal. 0 = never expires.
+    pub validity_period: i64,
+    /// Course that renews credentials on this track (default = none)
+    pub recertification_course: Pubkey,
+}
+
+pub fn handler(ctx: Context<CreateTrack>, params: CreateTrackParams) -> Result<()> {
+    require!(
+        !params.name.is_empty() && params.name.len() <= MAX_TRACK_NAME_LEN,
+        AcademyError::InvalidTrack
+    );
+    require!(
+        !params.uri_template.is_empty() && params.uri_template.len() <= MAX_TRACK_URI_LEN,
+        AcademyError::InvalidTrack
+    );
+    require!(
+        (1..=MAX_TRACK_LEVELS as u8).contains(&params.max_level),
+        AcademyError::InvalidTrack
+    );
+    require!(params.validity_period >= 0, AcademyError::InvalidTrack);
+    utils::require_academy_collection(&ctx.accounts.collection, &ctx.accounts.config.key())?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let track = &mut ctx.accounts.track;
+    track.schema_version = Track::SCHEMA_VERSION;
+    track.track_id = params.track_id;
+    track.collection = ctx.accounts.collection.key();
+    track.name = params.name.clone();
+    track.uri_template = params.uri_template;
+    track.max_level = params.max_level;
+    track.level_courses = Vec::new();
+    track.validity_period = params.validity_period;
+    track.recertification_course = params.recertification_course;
+    track.created_at = now;
+    track.updated_at = now;
+    track._reserved = [0u8; 24];
+    track.bump = ctx.bumps.track;
+
+    emit!(TrackCreated {
+        track_id: params.track_id,
+        collection: track.collection,
+        name: params.name,
+        max_level: params.max_level,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateTrackParams)]
+pub struct CreateTrack<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Track::SIZE,
+        seeds = [b"track", params.track_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub track: Account<'info, Track>,
+
+    /// CHECK: Metaplex Core collection; owner and update authority checked in handler.
+    pub collection: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,99 +1,263 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::AcademyError;
-use crate::events::Enrolled;
-use crate::state::{Course, Enrollment};
+use crate::events::{CohortEnrolled, Enrolled};
+use crate::state::{
+    AchievementReceipt, AchievementType, Cohort, CompletionReceipt, Config, Course, Enrollment,
+    LearnerProfile,
+};
+use crate::utils;
 
 pub fn handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, Enroll<'info>>,
     _course_id: String,
 ) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_ALL)?;
+
     let course = &mut ctx.accounts.course;
     let enrollment = &mut ctx.accounts.enrollment;
     let now = Clock::get()?.unix_timestamp;
 
     require!(course.is_active, AcademyError::CourseNotActive);
 
-    // Prerequisite check via remaining accounts:
-    //   remaining_accounts[0] = prerequisite Course PDA
-    //   remaining_accounts[1] = prerequisite Enrollment PDA (must belong to this learner)
-    if let Some(prerequisite_course) = course.prerequisite {
+    // A cohort's window and seats replace the course-level window; the
+    // course-level cap still bounds enrollments across all cohorts.
+    let cohort_seat = match ctx.accounts.cohort.as_mut() {
+        Some(cohort) => {
+            require!(
+                cohort.enrollment_open(now),
+                AcademyError::EnrollmentWindowClosed
+            );
+            require!(cohort.has_capacity(), AcademyError::CohortFull);
+            cohort.total_enrollments = cohort
+                .total_enrollments
+                .checked_add(1)
+                .ok_or(AcademyError::Overflow)?;
+            Some((cohort.key(), cohort.total_enrollments))
+        }
+        None => {
+            require!(!course.cohort_only, AcademyError::CohortRequired);
+            require!(
+                course.enrollment_open(now),
+                AcademyError::EnrollmentWindowClosed
+            );
+            None
+        }
+    };
+    require!(course.has_capacity(), AcademyError::CourseFull);
+
+    // Prerequisite check via remaining accounts, one proof per satisfied prerequisite:
+    //   [Course, Enrollment]                 — learner's completed enrollment
+    //   [Course, CompletionReceipt]          — left behind by closing a completed enrollment
+    //   [Course, credential asset, collection] — academy credential on the course's
+    //                                          track at or above its track_level
+    //   [AchievementType, AchievementReceipt] — learner was awarded the achievement
+    // Supply proofs for enough prerequisites to reach the course's min-count.
+    let prerequisites = course.prerequisites();
+    if !prerequisites.is_empty() {
         let remaining = ctx.remaining_accounts;
-        require!(remaining.len() >= 2, AcademyError::PrerequisiteNotMet);
+        let mut completed = Vec::new();
+        let mut i = 0;
+        while i < remaining.len() {
+            let (prerequisite, used) = verify_prerequisite(
+                &remaining[i..],
+                &prerequisites,
+                &ctx.accounts.learner.key(),
+                &ctx.accounts.config.key(),
+                ctx.program_id,
+                now,
+            )
+            .inspect_err(|_| {
+                msg!(
+                    "Prerequisite proof at account {} ({}) not met",
+                    i,
+                    remaining[i].key()
+                );
+            })?;
+            completed.push(prerequisite);
+            i += used;
+        }
 
-        let prereq_course_info = &remaining[0];
-        let prereq_enrollment_info = &remaining[1];
+        if !course.prerequisites_met(&completed) {
+            for missing in prerequisites.iter().filter(|key| !completed.contains(key)) {
+                msg!("Prerequisite not completed: {}", missing);
+            }
+            return err!(AcademyError::PrerequisiteNotMet);
+        }
+    }
 
+    enrollment.schema_version = Enrollment::SCHEMA_VERSION;
+    enrollment.course = course.key();
+    enrollment.enrolled_at = now;
+    enrollment.completed_at = None;
+    enrollment.lesson_flags = [0u64; 4];
+    enrollment.credential_asset = None;
+    enrollment.cohort = cohort_seat.map_or(Pubkey::default(), |(cohort, _)| cohort);
+    enrollment.attempt = 0;
+    enrollment.attempt_started_at = now;
+    enrollment.course_version = course.version;
+    enrollment._reserved = [0u8; 20];
+    enrollment.bump = ctx.bumps.enrollment;
+
+    ctx.accounts.learner_profile.init_if_new(
+        ctx.accounts.learner.key(),
+        now,
+        ctx.bumps.learner_profile,
+    );
+
+    course.total_enrollments = course
+        .total_enrollments
+        .checked_add(1)
+        .ok_or(AcademyError::Overflow)?;
+    course.open_enrollments = course
+        .open_enrollments
+        .checked_add(1)
+        .ok_or(AcademyError::Overflow)?;
+
+    emit!(Enrolled {
+        learner: ctx.accounts.learner.key(),
+        course: course.key(),
+        course_version: course.version,
+        timestamp: now,
+    });
+
+    if let Some((cohort, seat)) = cohort_seat {
+        emit!(CohortEnrolled {
+            learner: ctx.accounts.learner.key(),
+            course: course.key(),
+            cohort,
+            seat,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks the proof at the front of `accounts` and returns the prerequisite it
+/// satisfies together with the number of accounts it used.
+fn verify_prerequisite<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    prerequisites: &[Pubkey],
+    learner: &Pubkey,
+    config: &Pubkey,
+    program_id: &Pubkey,
+    now: i64,
+) -> Result<(Pubkey, usize)> {
+    require!(accounts.len() >= 2, AcademyError::PrerequisiteNotMet);
+    let prereq_info = &accounts[0];
+    let proof_info = &accounts[1];
+
+    require!(
+        prereq_info.owner == program_id,
+        AcademyError::PrerequisiteNotMet
+    );
+    // Verify the account is one of this course's prerequisites
+    require!(
+        prerequisites.contains(prereq_info.key),
+        AcademyError::PrerequisiteNotMet
+    );
+
+    if let Ok(achievement_type) = Account::<AchievementType>::try_from(prereq_info) {
         require!(
-            prereq_course_info.owner == ctx.program_id,
-            AcademyError::PrerequisiteNotMet
-        );
-        require!(
-            prereq_enrollment_info.owner == ctx.program_id,
+            proof_info.owner == program_id,
             AcademyError::PrerequisiteNotMet
         );
+        Account::<AchievementReceipt>::try_from(proof_info)
+            .map_err(|_| AcademyError::PrerequisiteNotMet)?;
 
-        // Verify the prerequisite course account matches
+        // Verify the receipt was issued to this learner via seed derivation
+        let (expected_pda, _) = Pubkey::find_program_address(
+            &[
+                b"achievement_receipt",
+                achievement_type.achievement_id.as_bytes(),
+                learner.as_ref(),
+            ],
+            program_id,
+        );
         require!(
-            prereq_course_info.key() == prerequisite_course,
+            proof_info.key() == expected_pda,
             AcademyError::PrerequisiteNotMet
         );
-        let prereq_course = Account::<Course>::try_from(prereq_course_info)
-            .map_err(|_| AcademyError::PrerequisiteNotMet)?;
+        return Ok((prereq_info.key(), 2));
+    }
 
-        let prereq_enrollment = Account::<Enrollment>::try_from(prereq_enrollment_info)
-            .map_err(|_| AcademyError::PrerequisiteNotMet)?;
+    let prereq_course =
+        Account::<Course>::try_from(prereq_info).map_err(|_| AcademyError::PrerequisiteNotMet)?;
 
+    if proof_info.owner == &mpl_core::ID {
+        require!(accounts.len() >= 3, AcademyError::PrerequisiteNotMet);
+        let (track_id, level, expires_at) =
+            utils::read_credential_track(proof_info, &accounts[2], config, learner)
+                .map_err(|_| AcademyError::PrerequisiteNotMet)?;
         require!(
-            prereq_enrollment.course == prerequisite_course,
+            prereq_course.credential_satisfies(track_id, level),
             AcademyError::PrerequisiteNotMet
         );
+        // Expired credentials must be renewed before they count again
         require!(
-            prereq_enrollment.completed_at.is_some(),
+            expires_at == 0 || now < expires_at,
             AcademyError::PrerequisiteNotMet
         );
+        return Ok((prereq_info.key(), 3));
+    }
+
+    require!(
+        proof_info.owner == program_id,
+        AcademyError::PrerequisiteNotMet
+    );
 
-        // Verify the enrollment PDA belongs to this learner via seed derivation
+    if Account::<CompletionReceipt>::try_from(proof_info).is_ok() {
+        // Verify the receipt belongs to this learner via seed derivation
         let (expected_pda, _) = Pubkey::find_program_address(
-            &[
-                b"enrollment",
-                prereq_course.course_id.as_bytes(),
-                ctx.accounts.learner.key().as_ref(),
-            ],
-            ctx.program_id,
+            &[b"completion", prereq_info.key.as_ref(), learner.as_ref()],
+            program_id,
         );
         require!(
-            prereq_enrollment_info.key() == expected_pda,
+            proof_info.key() == expected_pda,
             AcademyError::PrerequisiteNotMet
         );
+        return Ok((prereq_info.key(), 2));
     }
 
-    enrollment.course = course.key();
-    enrollment.enrolled_at = now;
-    enrollment.completed_at = None;
-    enrollment.lesson_flags = [0u64; 4];
-    enrollment.credential_asset = None;
-    enrollment._reserved = [0u8; 4];
-    enrollment.bump = ctx.bumps.enrollment;
+    let prereq_enrollment = Account::<Enrollment>::try_from(proof_info)
+        .map_err(|_| AcademyError::PrerequisiteNotMet)?;
 
-    course.total_enrollments = course
-        .total_enrollments
-        .checked_add(1)
-        .ok_or(AcademyError::Overflow)?;
+    require!(
+        prereq_enrollment.course == prereq_info.key(),
+        AcademyError::PrerequisiteNotMet
+    );
+    require!(
+        prereq_enrollment.completed_at.is_some(),
+        AcademyError::PrerequisiteNotMet
+    );
 
-    emit!(Enrolled {
-        learner: ctx.accounts.learner.key(),
-        course: course.key(),
-        course_version: course.version,
-        timestamp: now,
-    });
+    // Verify the enrollment PDA belongs to this learner via seed derivation
+    let (expected_pda, _) = Pubkey::find_program_address(
+        &[
+            b"enrollment",
+            prereq_course.course_id.as_bytes(),
+            learner.as_ref(),
+        ],
+        program_id,
+    );
+    require!(
+        proof_info.key() == expected_pda,
+        AcademyError::PrerequisiteNotMet
+    );
 
-    Ok(())
+    Ok((prereq_info.key(), 2))
 }
 
 #[derive(Accounts)]
 #[instruction(course_id: String)]
 pub struct Enroll<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [b"course", course_id.as_bytes()],
@@ -101,6 +265,14 @@ pub struct Enroll<'info> {
     )]
     pub course: Account<'info, Course>,
 
+    /// Cohort to join. Required when `course.cohort_only`.
+    #[account(
+        mut,
+        seeds = [b"cohort", course.key().as_ref(), &cohort.cohort_id.to_le_bytes()],
+        bump = cohort.bump,
+    )]
+    pub cohort: Option<Account<'info, Cohort>>,
+
     #[account(
         init,
         payer = learner,
@@ -110,6 +282,16 @@ pub struct Enroll<'info> {
     )]
     pub enrollment: Account<'info, Enrollment>,
 
+    /// Created on the learner's first enrollment
+    #[account(
+        init_if_needed,
+        payer = learner,
+        space = LearnerProfile::SIZE,
+        seeds = [b"learner", learner.key().as_ref()],
+        bump,
+    )]
+    pub learner_profile: Account<'info, LearnerProfile>,
+
     #[account(mut)]
     pub learner: Signer<'info>,
 
@@ -1,11 +1,16 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::AcademyError;
-use crate::events::CourseFinalized;
-use crate::state::{Config, Course, Enrollment};
+use crate::events::{CohortCompleted, CourseFinalized};
+use crate::state::{
+    before_deadline, BackendSigner, Cohort, Config, Course, CourseLessonXp, Enrollment,
+    LearnerProfile,
+};
 use crate::utils;
 
 pub fn handler(ctx: Context<FinalizeCourse>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_XP)?;
+
     let enrollment = &mut ctx.accounts.enrollment;
     let course = &mut ctx.accounts.course;
     let config = &ctx.accounts.config;
@@ -15,6 +20,14 @@ pub fn handler(ctx: Context<FinalizeCourse>) -> Result<()> {
         enrollment.completed_at.is_none(),
         AcademyError::CourseAlreadyFinalized
     );
+    require!(
+        !enrollment.progress_expired(course.progress_expires_after, now),
+        AcademyError::ProgressExpired
+    );
+    require!(
+        !enrollment.needs_lesson_migration(course.lesson_layout_version),
+        AcademyError::EnrollmentMigrationRequired
+    );
 
     let completed: u32 = enrollment.lesson_flags.iter().map(|w| w.count_ones()).sum();
     require!(
@@ -22,6 +35,35 @@ pub fn handler(ctx: Context<FinalizeCourse>) -> Result<()> {
         AcademyError::CourseNotCompleted
     );
 
+    // Cohort enrollments are held to the cohort's deadline instead of the course's
+    match ctx.accounts.cohort.as_mut() {
+        Some(cohort) => {
+            require_keys_eq!(
+                cohort.key(),
+                enrollment.cohort,
+                AcademyError::CohortMismatch
+            );
+            require!(
+                cohort.before_deadline(now),
+                AcademyError::CompletionDeadlinePassed
+            );
+            cohort.total_completions = cohort
+                .total_completions
+                .checked_add(1)
+                .ok_or(AcademyError::Overflow)?;
+        }
+        None => {
+            require!(
+                enrollment.cohort == Pubkey::default(),
+                AcademyError::CohortMismatch
+            );
+            require!(
+                before_deadline(course.completion_deadline, now),
+                AcademyError::CompletionDeadlinePassed
+            );
+        }
+    }
+
     enrollment.completed_at = Some(now);
 
     course.total_completions = course
@@ -31,11 +73,22 @@ pub fn handler(ctx: Context<FinalizeCourse>) -> Result<()> {
 
     let config_seeds: &[&[u8]] = &[b"config", &[config.bump]];
 
-    // Completion bonus = 50% of total lesson XP (rounded down)
-    let total_lesson_xp = (course.xp_per_lesson as u64)
-        .checked_mul(course.lesson_count as u64)
+    let total_lesson_xp = course.total_lesson_xp(ctx.accounts.lesson_xp.as_deref())?;
+    let bonus_xp = course.completion_bonus(total_lesson_xp)?;
+
+    utils::require_xp_token_account(
+        &ctx.accounts.learner_token_account,
+        &config.xp_mint,
+        &ctx.accounts.learner.key(),
+    )?;
+
+    let profile = &mut ctx.accounts.learner_profile;
+    profile.record_xp(bonus_xp, now, config.daily_xp_cap)?;
+    profile.courses_completed = profile
+        .courses_completed
+        .checked_add(1)
         .ok_or(AcademyError::Overflow)?;
-    let bonus_xp = total_lesson_xp / 2;
+    profile.record_activity(now);
 
     if bonus_xp > 0 {
         utils::mint_xp(
@@ -67,15 +120,22 @@ pub fn handler(ctx: Context<FinalizeCourse>) -> Result<()> {
     emit!(CourseFinalized {
         learner: ctx.accounts.learner.key(),
         course: course.key(),
-        total_xp: completed
-            .checked_mul(course.xp_per_lesson)
-            .ok_or(AcademyError::Overflow)?,
+        total_xp: total_lesson_xp,
         bonus_xp,
         creator: ctx.accounts.creator.key(),
         creator_xp,
         timestamp: now,
     });
 
+    if let Some(cohort) = &ctx.accounts.cohort {
+        emit!(CohortCompleted {
+            learner: ctx.accounts.learner.key(),
+            course: course.key(),
+            cohort: cohort.key(),
+            timestamp: now,
+        });
+    }
+
     Ok(())
 }
 
@@ -94,6 +154,21 @@ pub struct FinalizeCourse<'info> {
     )]
     pub course: Account<'info, Course>,
 
+    /// Per-lesson XP table. Required when `course.has_lesson_xp`.
+    #[account(
+        seeds = [b"lesson_xp", course.key().as_ref()],
+        bump = lesson_xp.bump,
+    )]
+    pub lesson_xp: Option<Account<'info, CourseLessonXp>>,
+
+    /// The enrollment's cohort. Required when `enrollment.cohort` is set.
+    #[account(
+        mut,
+        seeds = [b"cohort", course.key().as_ref(), &cohort.cohort_id.to_le_bytes()],
+        bump = cohort.bump,
+    )]
+    pub cohort: Option<Account<'info, Cohort>>,
+
     #[account(
         mut,
         seeds = [b"enrollment", course.course_id.as_bytes(), learner.key().as_ref()],
@@ -102,6 +177,13 @@ pub struct FinalizeCourse<'info> {
     )]
     pub enrollment: Account<'info, Enrollment>,
 
+    #[account(
+        mut,
+        seeds = [b"learner", learner.key().as_ref()],
+        bump = learner_profile.bump,
+    )]
+    pub learner_profile: Account<'info, LearnerProfile>,
+
     /// CHECK: Tied to enrollment PDA via seeds constraint.
     pub learner: AccountInfo<'info>,
 
@@ -133,10 +215,22 @@ pub struct FinalizeCourse<'info> {
     pub xp_mint: AccountInfo<'info>,
 
     #[account(
-        constraint = backend_signer.key() == config.backend_signer @ AcademyError::Unauthorized,
+        constraint = utils::is_backend_signer(
+            &config,
+            backend_signer_role.as_deref(),
+            &backend_signer.key(),
+            BackendSigner::SCOPE_FINALIZE,
+        )? @ AcademyError::Unauthorized,
     )]
     pub backend_signer: Signer<'info>,
 
+    /// Registry entry for `backend_signer`. Omit when signing as `config.backend_signer`.
+    #[account(
+        seeds = [b"backend_signer", backend_signer.key().as_ref()],
+        bump = backend_signer_role.bump,
+    )]
+    pub backend_signer_role: Option<Account<'info, BackendSigner>>,
+
     /// CHECK: Validated by address constraint.
     #[account(address = spl_token_2022::id())]
     pub token_program: AccountInfo<'info>,
@@ -76,21 +76,32 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     // call spl_token_metadata_interface::initialize after this instruction.
 
     let config = &mut ctx.accounts.config;
+    config.schema_version = Config::SCHEMA_VERSION;
     config.authority = ctx.accounts.authority.key();
     config.backend_signer = ctx.accounts.authority.key();
     config.xp_mint = mint_key;
-    config._reserved = [0u8; 8];
+    config.daily_xp_cap = 0; // no cap until set via update_config
+    config.pending_authority = Pubkey::default();
+    config.paused = 0;
+    config.guardian = Pubkey::default();
+    config._reserved = [0u8; 63];
     config.bump = bump;
 
     // Auto-register authority as a minter (backend_signer defaults to authority)
     let minter_role = &mut ctx.accounts.backend_minter_role;
+    minter_role.schema_version = MinterRole::SCHEMA_VERSION;
     minter_role.minter = ctx.accounts.authority.key();
     minter_role.label = "backend".to_string();
     minter_role.max_xp_per_call = 0; // unlimited
     minter_role.total_xp_minted = 0;
     minter_role.is_active = true;
     minter_role.created_at = Clock::get()?.unix_timestamp;
-    minter_role._reserved = [0u8; 8];
+    minter_role.window_seconds = 0;
+    minter_role.max_xp_per_window = 0; // unlimited
+    minter_role.window_started_at = 0;
+    minter_role.window_xp_minted = 0;
+    minter_role.max_total_xp = 0; // unlimited
+    minter_role._reserved = [0u8; 32];
     minter_role.bump = ctx.bumps.backend_minter_role;
 
     Ok(())
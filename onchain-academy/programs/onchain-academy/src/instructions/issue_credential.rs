@@ -1,91 +1,194 @@
 use anchor_lang::prelude::*;
-use mpl_core::{
-    instructions::CreateV2CpiBuilder,
-    types::{
-        Attribute, Attributes, PermanentFreezeDelegate, Plugin, PluginAuthority,
-        PluginAuthorityPair,
-    },
-};
 
 use crate::errors::AcademyError;
-use crate::events::CredentialIssued;
-use crate::state::{Config, Course, Enrollment};
-
-pub fn handler(
-    ctx: Context<IssueCredential>,
-    credential_name: String,
-    metadata_uri: String,
-    courses_completed: u32,
-    total_xp: u64,
-) -> Result<()> {
-    let enrollment = &ctx.accounts.enrollment;
+use crate::events::{CredentialIssued, CredentialUpgraded};
+use crate::state::{
+    BackendSigner, CompletionReceipt, Config, Course, Enrollment, LearnerProfile, Track,
+    TrackCredential,
+};
+use crate::utils::{self, CredentialCpi};
+
+/// Credits a completed enrollment to the learner's track credential: the first
+/// enrollment on a track mints the asset, later ones upgrade it in place. The
+/// completion is read from the live Enrollment or, once the learner has closed
+/// it, from its CompletionReceipt; exactly one of the two must be passed.
+/// Name, URI and attributes are all derived from the track, course and profile.
+/// After `revoke_credential` the next issue mints a fresh asset. A learner
+/// holding a credential minted before `TrackCredential` existed must pass that
+/// asset (not a new keypair); it is adopted and upgraded instead of minting.
+pub fn handler(ctx: Context<IssueCredential>) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_not_paused(Config::PAUSE_CREDENTIALS)?;
+
     let course = &ctx.accounts.course;
-    let config = &ctx.accounts.config;
+    let track = &ctx.accounts.track;
+    let profile = &ctx.accounts.learner_profile;
 
-    require!(
-        enrollment.completed_at.is_some(),
-        AcademyError::CourseNotFinalized
-    );
+    let (completed_at, credential_asset) = match (
+        ctx.accounts.enrollment.as_deref(),
+        ctx.accounts.completion_receipt.as_deref(),
+    ) {
+        (Some(enrollment), None) => (enrollment.completed_at, enrollment.credential_asset),
+        (None, Some(receipt)) => (Some(receipt.completed_at), receipt.credential_asset),
+        _ => return err!(AcademyError::InvalidCredentialSource),
+    };
+    require!(completed_at.is_some(), AcademyError::CourseNotFinalized);
 
     require!(
-        enrollment.credential_asset.is_none(),
+        credential_asset.is_none(),
         AcademyError::CredentialAlreadyIssued
     );
+    require!(
+        track.level_of(&course.key()) == Some(course.track_level),
+        AcademyError::CourseNotInTrack
+    );
 
-    let config_bump = config.bump;
+    let config_bump = ctx.accounts.config.bump;
     let config_seeds: &[&[u8]] = &[b"config", &[config_bump]];
-    let signer_seeds = &[config_seeds];
-
-    CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
-        .asset(&ctx.accounts.credential_asset.to_account_info())
-        .collection(Some(&ctx.accounts.track_collection))
-        .payer(&ctx.accounts.payer.to_account_info())
-        .owner(Some(&ctx.accounts.learner))
-        .authority(Some(&ctx.accounts.config.to_account_info()))
-        .system_program(&ctx.accounts.system_program.to_account_info())
-        .name(credential_name)
-        .uri(metadata_uri)
-        .plugins(vec![
-            PluginAuthorityPair {
-                plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
-                authority: Some(PluginAuthority::UpdateAuthority),
-            },
-            PluginAuthorityPair {
-                plugin: Plugin::Attributes(Attributes {
-                    attribute_list: vec![
-                        Attribute {
-                            key: "track_id".into(),
-                            value: course.track_id.to_string(),
-                        },
-                        Attribute {
-                            key: "level".into(),
-                            value: course.track_level.to_string(),
-                        },
-                        Attribute {
-                            key: "courses_completed".into(),
-                            value: courses_completed.to_string(),
-                        },
-                        Attribute {
-                            key: "total_xp".into(),
-                            value: total_xp.to_string(),
-                        },
-                    ],
-                }),
-                authority: Some(PluginAuthority::UpdateAuthority),
-            },
-        ])
-        .invoke_signed(signer_seeds)?;
-
-    let enrollment_mut = &mut ctx.accounts.enrollment;
-    enrollment_mut.credential_asset = Some(ctx.accounts.credential_asset.key());
-
-    emit!(CredentialIssued {
-        learner: ctx.accounts.learner.key(),
-        track_id: course.track_id,
-        credential_asset: ctx.accounts.credential_asset.key(),
-        current_level: course.track_level,
-        timestamp: Clock::get()?.unix_timestamp,
-    });
+    let config_info = ctx.accounts.config.to_account_info();
+    let payer_info = ctx.accounts.payer.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+    let cpi = CredentialCpi {
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        asset: &ctx.accounts.credential_asset,
+        collection: &ctx.accounts.track_collection,
+        config: &config_info,
+        payer: &payer_info,
+        system_program: &system_program_info,
+        config_seeds,
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let asset_key = ctx.accounts.credential_asset.key();
+    let credential = &mut ctx.accounts.track_credential;
+    // A new signer keypair mints; an existing asset on a new TrackCredential
+    // is a legacy credential being adopted.
+    let minted =
+        credential.is_revoked() || (credential.is_new() && ctx.accounts.credential_asset.is_signer);
+    let adopted = credential.is_new() && !minted;
+
+    let level = if minted {
+        let expires_at = track.credential_expiry(now);
+        require!(
+            ctx.accounts.credential_asset.is_signer,
+            AcademyError::CredentialAssetMustSign
+        );
+        cpi.create(
+            &ctx.accounts.learner,
+            track.credential_name(course.track_level),
+            track.credential_uri(course.track_level, ctx.accounts.learner.key),
+            utils::credential_attributes(
+                course.track_id,
+                course.track_level,
+                profile.courses_completed,
+                profile.total_xp,
+                now,
+                expires_at,
+            ),
+        )?;
+
+        credential.schema_version = TrackCredential::SCHEMA_VERSION;
+        credential.learner = ctx.accounts.learner.key();
+        credential.track_id = course.track_id;
+        credential.asset = asset_key;
+        credential.courses_credited = 0;
+        credential.issued_at = now;
+        credential.revoked_at = 0;
+        credential.expires_at = expires_at;
+        credential.renewed_at = 0;
+        credential._reserved = [0u8; 40];
+        credential.bump = ctx.bumps.track_credential;
+        course.track_level
+    } else {
+        if !adopted {
+            require!(
+                asset_key == credential.asset,
+                AcademyError::CredentialAssetMismatch
+            );
+        }
+        let (asset_track_id, asset_level, _) = utils::read_credential_track(
+            &ctx.accounts.credential_asset,
+            &ctx.accounts.track_collection,
+            &ctx.accounts.config.key(),
+            &ctx.accounts.learner.key(),
+        )?;
+        require!(
+            asset_track_id == course.track_id,
+            AcademyError::CredentialAssetMismatch
+        );
+
+        // A lower-level course still counts towards the credential but never
+        // lowers its level.
+        let level = asset_level.max(course.track_level);
+        // Adopted credentials start their validity period now
+        let (issued_at, expires_at) = if adopted {
+            (now, track.credential_expiry(now))
+        } else {
+            (credential.issued_at, credential.expires_at)
+        };
+        cpi.update(
+            track.credential_name(level),
+            track.credential_uri(level, ctx.accounts.learner.key),
+            utils::credential_attributes(
+                course.track_id,
+                level,
+                profile.courses_completed,
+                profile.total_xp,
+                issued_at,
+                expires_at,
+            ),
+        )?;
+
+        if adopted {
+            // The enrollment that minted the legacy asset counts as credited
+            credential.schema_version = TrackCredential::SCHEMA_VERSION;
+            credential.learner = ctx.accounts.learner.key();
+            credential.track_id = course.track_id;
+            credential.asset = asset_key;
+            credential.courses_credited = 1;
+            credential.issued_at = issued_at;
+            credential.revoked_at = 0;
+            credential.expires_at = expires_at;
+            credential.renewed_at = 0;
+            credential._reserved = [0u8; 40];
+            credential.bump = ctx.bumps.track_credential;
+        }
+        level
+    };
+
+    credential.level = level;
+    credential.courses_credited = credential
+        .courses_credited
+        .checked_add(1)
+        .ok_or(AcademyError::Overflow)?;
+    credential.updated_at = now;
+
+    if let Some(enrollment) = ctx.accounts.enrollment.as_mut() {
+        enrollment.credential_asset = Some(asset_key);
+    }
+    if let Some(receipt) = ctx.accounts.completion_receipt.as_mut() {
+        receipt.credential_asset = Some(asset_key);
+    }
+
+    if minted {
+        emit!(CredentialIssued {
+            learner: ctx.accounts.learner.key(),
+            track_id: course.track_id,
+            credential_asset: asset_key,
+            current_level: level,
+            expires_at: credential.expires_at,
+            timestamp: now,
+        });
+    } else {
+        emit!(CredentialUpgraded {
+            learner: ctx.accounts.learner.key(),
+            track_id: course.track_id,
+            credential_asset: asset_key,
+            current_level: level,
+            timestamp: now,
+        });
+    }
 
     Ok(())
 }
@@ -104,33 +207,81 @@ pub struct IssueCredential<'info> {
     )]
     pub course: Account<'info, Course>,
 
+    /// Completed enrollment to credit. Omit when passing `completion_receipt`.
     #[account(
         mut,
         seeds = [b"enrollment", course.course_id.as_bytes(), learner.key().as_ref()],
         bump = enrollment.bump,
         constraint = enrollment.course == course.key() @ AcademyError::EnrollmentCourseMismatch,
     )]
-    pub enrollment: Account<'info, Enrollment>,
+    pub enrollment: Option<Account<'info, Enrollment>>,
+
+    /// Receipt left by closing the completed enrollment. Omit when passing `enrollment`.
+    #[account(
+        mut,
+        seeds = [b"completion", course.key().as_ref(), learner.key().as_ref()],
+        bump = completion_receipt.bump,
+    )]
+    pub completion_receipt: Option<Account<'info, CompletionReceipt>>,
+
+    #[account(
+        seeds = [b"learner", learner.key().as_ref()],
+        bump = learner_profile.bump,
+    )]
+    pub learner_profile: Account<'info, LearnerProfile>,
+
+    /// Registry entry for the course's track
+    #[account(
+        seeds = [b"track", course.track_id.to_le_bytes().as_ref()],
+        bump = track.bump,
+    )]
+    pub track: Account<'info, Track>,
+
+    /// Learner's credential on the course's track. Created on first issue.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TrackCredential::SIZE,
+        seeds = [b"credential", course.track_id.to_le_bytes().as_ref(), learner.key().as_ref()],
+        bump,
+    )]
+    pub track_credential: Account<'info, TrackCredential>,
 
-    /// CHECK: Tied to enrollment PDA via seeds constraint.
+    /// CHECK: Tied to enrollment or receipt PDA via seeds constraint.
     pub learner: AccountInfo<'info>,
 
-    /// New credential NFT asset keypair — must sign the transaction.
+    /// CHECK: New asset keypair (must sign) on first issue, the learner's legacy
+    /// asset when adopting, otherwise validated against `track_credential.asset`.
     #[account(mut)]
-    pub credential_asset: Signer<'info>,
+    pub credential_asset: AccountInfo<'info>,
 
-    /// CHECK: Metaplex Core collection for this track. Validated by Metaplex Core CPI.
-    #[account(mut)]
+    /// CHECK: Metaplex Core collection for this track. Checked against the track registry.
+    #[account(
+        mut,
+        constraint = track_collection.key() == track.collection @ AcademyError::TrackCollectionMismatch,
+    )]
     pub track_collection: AccountInfo<'info>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
 
     #[account(
-        constraint = backend_signer.key() == config.backend_signer @ AcademyError::Unauthorized,
+        constraint = utils::is_backend_signer(
+            &config,
+            backend_signer_role.as_deref(),
+            &backend_signer.key(),
+            BackendSigner::SCOPE_CREDENTIALS,
+        )? @ AcademyError::Unauthorized,
     )]
     pub backend_signer: Signer<'info>,
 
+    /// Registry entry for `backend_signer`. Omit when signing as `config.backend_signer`.
+    #[account(
+        seeds = [b"backend_signer", backend_signer.key().as_ref()],
+        bump = backend_signer_role.bump,
+    )]
+    pub backend_signer_role: Option<Account<'info, BackendSigner>>,
+
     /// CHECK: Metaplex Core program.
     #[account(address = mpl_core::ID)]
     pub mpl_core_program: AccountInfo<'info>,
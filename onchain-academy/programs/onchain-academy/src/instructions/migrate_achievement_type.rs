@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::AccountMigrated;
+use crate::state::{AchievementType, AchievementTypeV0, Config};
+use crate::utils;
+
+/// Grows a schema 0 (338-byte) AchievementType to the current layout.
+pub fn handler(ctx: Context<MigrateAchievementType>) -> Result<()> {
+    let achievement_info = ctx.accounts.achievement_type.to_account_info();
+    let legacy: AchievementTypeV0 = utils::read_legacy_account(
+        &achievement_info,
+        AchievementType::DISCRIMINATOR,
+        AchievementType::LEGACY_SIZE,
+    )?;
+
+    let (expected_pda, _) = Pubkey::find_program_address(
+        &[b"achievement", legacy.achievement_id.as_bytes()],
+        ctx.program_id,
+    );
+    require_keys_eq!(
+        achievement_info.key(),
+        expected_pda,
+        AcademyError::InvalidMigrationSource
+    );
+
+    utils::grow_account(
+        &achievement_info,
+        AchievementType::SIZE,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let achievement = AchievementType {
+        schema_version: AchievementType::SCHEMA_VERSION,
+        achievement_id: legacy.achievement_id,
+        name: legacy.name,
+        metadata_uri: legacy.metadata_uri,
+        collection: legacy.collection,
+        creator: legacy.creator,
+        max_supply: legacy.max_supply,
+        current_supply: legacy.current_supply,
+        xp_reward: legacy.xp_reward,
+        is_active: legacy.is_active,
+        created_at: legacy.created_at,
+        revoked_count: 0,
+        _reserved: [0u8; 28],
+        bump: legacy.bump,
+    };
+    achievement.try_serialize(&mut &mut achievement_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: achievement_info.key(),
+        account_type: "achievement_type".to_string(),
+        from_version: 0,
+        to_version: AchievementType::SCHEMA_VERSION,
+        new_size: AchievementType::SIZE as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateAchievementType<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Schema 0 AchievementType that no longer deserializes as `AchievementType`.
+    /// Owner checked here; size, discriminator and PDA checked in handler.
+    #[account(mut, owner = crate::ID)]
+    pub achievement_type: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
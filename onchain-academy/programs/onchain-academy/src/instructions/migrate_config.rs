@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::AccountMigrated;
+use crate::state::{Config, ConfigV0};
+use crate::utils;
+
+/// Grows the schema 0 (113-byte) Config singleton to the current layout.
+/// Must run before any other instruction after the upgrade, since every
+/// handler loads Config.
+pub fn handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    let legacy: ConfigV0 =
+        utils::read_legacy_account(&config_info, Config::DISCRIMINATOR, Config::LEGACY_SIZE)?;
+
+    require_keys_eq!(
+        legacy.authority,
+        ctx.accounts.authority.key(),
+        AcademyError::Unauthorized
+    );
+
+    utils::grow_account(
+        &config_info,
+        Config::SIZE,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let config = Config {
+        schema_version: Config::SCHEMA_VERSION,
+        authority: legacy.authority,
+        backend_signer: legacy.backend_signer,
+        xp_mint: legacy.xp_mint,
+        daily_xp_cap: legacy.daily_xp_cap,
+        pending_authority: Pubkey::default(),
+        paused: 0,
+        guardian: Pubkey::default(),
+        _reserved: [0u8; 63],
+        bump: legacy.bump,
+    };
+    config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: config_info.key(),
+        account_type: "config".to_string(),
+        from_version: 0,
+        to_version: Config::SCHEMA_VERSION,
+        new_size: Config::SIZE as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Schema 0 Config that no longer deserializes as `Config`.
+    /// Seeds and owner checked here; size and discriminator checked in handler.
+    #[account(mut, seeds = [b"config"], bump, owner = crate::ID)]
+    pub config: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
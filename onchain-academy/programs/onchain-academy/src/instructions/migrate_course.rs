@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::AccountMigrated;
+use crate::state::{Config, Course, CourseV0};
+use crate::utils;
+
+/// Grows an unversioned (192-byte) Course to the current layout. The implicit
+/// 50% completion bonus becomes an explicit `completion_bonus_xp`.
+pub fn handler(ctx: Context<MigrateCourse>) -> Result<()> {
+    let course_info = ctx.accounts.course.to_account_info();
+    let course = read_v0(&course_info)?;
+
+    let (expected_pda, _) =
+        Pubkey::find_program_address(&[b"course", course.course_id.as_bytes()], ctx.program_id);
+    require_keys_eq!(
+        course_info.key(),
+        expected_pda,
+        AcademyError::InvalidMigrationSource
+    );
+
+    utils::grow_account(
+        &course_info,
+        Course::SIZE,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    course.try_serialize(&mut &mut course_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: course_info.key(),
+        account_type: "course".to_string(),
+        from_version: 0,
+        to_version: Course::SCHEMA_VERSION,
+        new_size: Course::SIZE as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+fn read_v0(course_info: &AccountInfo) -> Result<Course> {
+    let legacy: CourseV0 =
+        utils::read_legacy_account(course_info, Course::DISCRIMINATOR, Course::LEGACY_SIZE)?;
+
+    Ok(Course {
+        schema_version: Course::SCHEMA_VERSION,
+        course_id: legacy.course_id,
+        creator: legacy.creator,
+        content_tx_id: legacy.content_tx_id,
+        version: legacy.version,
+        lesson_count: legacy.lesson_count,
+        difficulty: legacy.difficulty,
+        xp_per_lesson: legacy.xp_per_lesson,
+        track_id: legacy.track_id,
+        track_level: legacy.track_level,
+        prerequisite: legacy.prerequisite,
+        creator_reward_xp: legacy.creator_reward_xp,
+        min_completions_for_reward: legacy.min_completions_for_reward,
+        total_completions: legacy.total_completions,
+        total_enrollments: legacy.total_enrollments,
+        is_active: legacy.is_active,
+        created_at: legacy.created_at,
+        updated_at: legacy.updated_at,
+        completion_bonus_xp: 0,
+        has_lesson_xp: false,
+        additional_prerequisites: [Pubkey::default(); 3],
+        prerequisite_min_count: 0,
+        enroll_opens_at: 0,
+        enroll_closes_at: 0,
+        completion_deadline: 0,
+        max_enrollments: 0,
+        cohort_only: false,
+        progress_expires_after: 0,
+        lesson_layout_version: 0,
+        // Closes were never counted, so assume every enrollment is still open;
+        // close_enrollment saturates, so this can only overestimate
+        open_enrollments: legacy.total_enrollments,
+        author: Pubkey::default(),
+        completion_bonus_bps: Course::LEGACY_COMPLETION_BONUS_BPS,
+        _reserved: [0u8; 77],
+        bump: legacy.bump,
+    })
+}
+
+#[derive(Accounts)]
+pub struct MigrateCourse<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Unversioned Course that no longer deserializes as `Course`.
+    /// Owner checked here; size, discriminator and PDA checked in handler.
+    #[account(mut, owner = crate::ID)]
+    pub course: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
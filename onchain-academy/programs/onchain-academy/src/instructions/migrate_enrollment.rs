@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::AccountMigrated;
+use crate::state::{Config, Course, Enrollment, EnrollmentV0, LearnerProfile};
+use crate::utils;
+
+/// Grows a learner's schema 0 (127-byte) Enrollment to the current layout.
+/// Permissionless: any payer can migrate any learner's enrollment and covers
+/// the extra rent, which goes to the learner on `close_enrollment`. Also creates the learner's LearnerProfile if needed and backfills it from
+/// the old enrollment and the learner's XP balance.
+pub fn handler(ctx: Context<MigrateEnrollment>) -> Result<()> {
+    let enrollment_info = ctx.accounts.enrollment.to_account_info();
+    let legacy: EnrollmentV0 = utils::read_legacy_account(
+        &enrollment_info,
+        Enrollment::DISCRIMINATOR,
+        Enrollment::LEGACY_SIZE,
+    )?;
+
+    require_keys_eq!(
+        legacy.course,
+        ctx.accounts.course.key(),
+        AcademyError::EnrollmentCourseMismatch
+    );
+
+    utils::grow_account(
+        &enrollment_info,
+        Enrollment::SIZE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let enrollment = Enrollment {
+        schema_version: Enrollment::SCHEMA_VERSION,
+        course: legacy.course,
+        enrolled_at: legacy.enrolled_at,
+        completed_at: legacy.completed_at,
+        lesson_flags: legacy.lesson_flags,
+        credential_asset: legacy.credential_asset,
+        cohort: Pubkey::default(),
+        attempt: 0,
+        attempt_started_at: legacy.enrolled_at,
+        course_version: 0,
+        _reserved: [0u8; 20],
+        bump: legacy.bump,
+    };
+    enrollment.try_serialize(&mut &mut enrollment_info.try_borrow_mut_data()?[..])?;
+
+    let learner = ctx.accounts.learner.key();
+    let xp_balance = match &ctx.accounts.learner_token_account {
+        Some(token_account) => {
+            utils::xp_token_balance(token_account, &ctx.accounts.config.xp_mint, &learner)?
+        }
+        None => 0,
+    };
+    let now = Clock::get()?.unix_timestamp;
+    let profile = &mut ctx.accounts.learner_profile;
+    profile.init_if_new(learner, now, ctx.bumps.learner_profile);
+    profile.backfill_legacy(legacy.completed_at.is_some(), xp_balance)?;
+
+    emit!(AccountMigrated {
+        account: enrollment_info.key(),
+        account_type: "enrollment".to_string(),
+        from_version: 0,
+        to_version: Enrollment::SCHEMA_VERSION,
+        new_size: Enrollment::SIZE as u32,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateEnrollment<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    /// CHECK: Schema 0 Enrollment that no longer deserializes as `Enrollment`.
+    /// Seeds tie it to this learner; size and discriminator checked in handler.
+    #[account(
+        mut,
+        seeds = [b"enrollment", course.course_id.as_bytes(), learner.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub enrollment: AccountInfo<'info>,
+
+    /// Created here for learners who have not enrolled since the upgrade
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LearnerProfile::SIZE,
+        seeds = [b"learner", learner.key().as_ref()],
+        bump,
+    )]
+    pub learner_profile: Account<'info, LearnerProfile>,
+
+    /// CHECK: Learner's XP token account; its balance seeds `total_xp`.
+    /// Omit if the learner has never earned XP. Mint and owner validated in handler.
+    pub learner_token_account: Option<AccountInfo<'info>>,
+
+    /// CHECK: Tied to enrollment and profile PDAs via seeds constraints.
+    pub learner: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
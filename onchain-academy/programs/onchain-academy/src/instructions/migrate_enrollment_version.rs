@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::EnrollmentVersionMigrated;
+use crate::state::{Config, Course, Enrollment, LessonMap};
+
+/// Remaps an enrollment's `lesson_flags` through one `LessonMap`. Courses
+/// restructured several times need one call per map, oldest first.
+/// Permissionless: the result is fully determined by the published map.
+pub fn handler(ctx: Context<MigrateEnrollmentVersion>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_ALL)?;
+
+    let lesson_map = &ctx.accounts.lesson_map;
+    let enrollment = &mut ctx.accounts.enrollment;
+    let from_version = enrollment.course_version;
+
+    require!(
+        lesson_map.applies_to(from_version),
+        AcademyError::LessonMapNotApplicable
+    );
+
+    enrollment.lesson_flags = lesson_map.remap(&enrollment.lesson_flags);
+    enrollment.course_version = lesson_map.to_version;
+
+    emit!(EnrollmentVersionMigrated {
+        learner: ctx.accounts.learner.key(),
+        course: ctx.accounts.course.key(),
+        from_version,
+        to_version: lesson_map.to_version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateEnrollmentVersion<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [b"lesson_map", course.key().as_ref(), &lesson_map.to_version.to_le_bytes()],
+        bump = lesson_map.bump,
+    )]
+    pub lesson_map: Account<'info, LessonMap>,
+
+    #[account(
+        mut,
+        seeds = [b"enrollment", course.course_id.as_bytes(), learner.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.course == course.key() @ AcademyError::EnrollmentCourseMismatch,
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+
+    /// CHECK: Tied to enrollment PDA via seeds constraint.
+    pub learner: AccountInfo<'info>,
+}
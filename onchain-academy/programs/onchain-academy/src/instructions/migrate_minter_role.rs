@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::AccountMigrated;
+use crate::instructions::register_minter::validate_minter_limits;
+use crate::state::{Config, MinterRole, MinterRoleV0};
+use crate::utils;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateMinterRoleParams {
+    pub window_seconds: i64,
+    pub max_xp_per_window: u64,
+    pub max_total_xp: u64,
+}
+
+/// Grows a schema 0 (110-byte) MinterRole to the current layout and applies
+/// the given rate limits. Counters and flags from the old account are preserved.
+pub fn handler(ctx: Context<MigrateMinterRole>, params: MigrateMinterRoleParams) -> Result<()> {
+    validate_minter_limits(params.window_seconds, params.max_xp_per_window)?;
+
+    let role_info = ctx.accounts.minter_role.to_account_info();
+    let legacy: MinterRoleV0 = utils::read_legacy_account(
+        &role_info,
+        MinterRole::DISCRIMINATOR,
+        MinterRole::LEGACY_SIZE,
+    )?;
+
+    let (expected_pda, _) =
+        Pubkey::find_program_address(&[b"minter", legacy.minter.as_ref()], ctx.program_id);
+    require_keys_eq!(
+        role_info.key(),
+        expected_pda,
+        AcademyError::InvalidMigrationSource
+    );
+
+    utils::grow_account(
+        &role_info,
+        MinterRole::SIZE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let role = MinterRole {
+        schema_version: MinterRole::SCHEMA_VERSION,
+        minter: legacy.minter,
+        label: legacy.label,
+        max_xp_per_call: legacy.max_xp_per_call,
+        total_xp_minted: legacy.total_xp_minted,
+        is_active: legacy.is_active,
+        created_at: legacy.created_at,
+        window_seconds: params.window_seconds,
+        max_xp_per_window: params.max_xp_per_window,
+        window_started_at: 0,
+        window_xp_minted: 0,
+        max_total_xp: params.max_total_xp,
+        _reserved: [0u8; 32],
+        bump: legacy.bump,
+    };
+    role.try_serialize(&mut &mut role_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: role_info.key(),
+        account_type: "minter_role".to_string(),
+        from_version: 0,
+        to_version: MinterRole::SCHEMA_VERSION,
+        new_size: MinterRole::SIZE as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateMinterRole<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Schema 0 MinterRole that no longer deserializes as `MinterRole`.
+    /// Owner checked here; size, discriminator and PDA checked in handler.
+    #[account(mut, owner = crate::ID)]
+    pub minter_role: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,33 +1,94 @@
+pub mod accept_authority;
+pub mod add_backend_signer;
+pub mod add_course_author;
 pub mod award_achievement;
+pub mod cancel_authority_transfer;
+pub mod close_course;
 pub mod close_enrollment;
+pub mod close_orphaned_enrollment;
 pub mod complete_lesson;
+pub mod complete_lesson_attested;
+pub mod complete_lessons;
 pub mod create_achievement_type;
+pub mod create_cohort;
 pub mod create_course;
+pub mod create_track;
 pub mod deactivate_achievement_type;
 pub mod enroll;
 pub mod finalize_course;
 pub mod initialize;
 pub mod issue_credential;
+pub mod migrate_achievement_type;
+pub mod migrate_config;
+pub mod migrate_course;
+pub mod migrate_enrollment;
+pub mod migrate_enrollment_version;
+pub mod migrate_minter_role;
+pub mod propose_authority;
 pub mod register_minter;
+pub mod remove_backend_signer;
+pub mod remove_course_author;
+pub mod remove_lesson_xp;
+pub mod renew_credential;
+pub mod reset_enrollment;
+pub mod restructure_course;
+pub mod revoke_achievement;
+pub mod revoke_credential;
 pub mod revoke_minter;
 pub mod reward_xp;
+pub mod set_lesson_xp;
+pub mod set_paused;
+pub mod set_track_courses;
+pub mod update_cohort;
 pub mod update_config;
 pub mod update_course;
+pub mod update_track;
 pub mod upgrade_credential;
+pub mod verify_credential;
 
+pub use accept_authority::*;
+pub use add_backend_signer::*;
+pub use add_course_author::*;
 pub use award_achievement::*;
+pub use cancel_authority_transfer::*;
+pub use close_course::*;
 pub use close_enrollment::*;
+pub use close_orphaned_enrollment::*;
 pub use complete_lesson::*;
+pub use complete_lesson_attested::*;
 pub use create_achievement_type::*;
+pub use create_cohort::*;
 pub use create_course::*;
+pub use create_track::*;
 pub use deactivate_achievement_type::*;
 pub use enroll::*;
 pub use finalize_course::*;
 pub use initialize::*;
 pub use issue_credential::*;
+pub use migrate_achievement_type::*;
+pub use migrate_config::*;
+pub use migrate_course::*;
+pub use migrate_enrollment::*;
+pub use migrate_enrollment_version::*;
+pub use migrate_minter_role::*;
+pub use propose_authority::*;
 pub use register_minter::*;
+pub use remove_backend_signer::*;
+pub use remove_course_author::*;
+pub use remove_lesson_xp::*;
+pub use renew_credential::*;
+pub use reset_enrollment::*;
+pub use restructure_course::*;
+pub use revoke_achievement::*;
+pub use revoke_credential::*;
 pub use revoke_minter::*;
 pub use reward_xp::*;
+pub use set_lesson_xp::*;
+pub use set_paused::*;
+pub use set_track_courses::*;
+pub use update_cohort::*;
 pub use update_config::*;
 pub use update_course::*;
+pub use update_track::*;
 pub use upgrade_credential::*;
+pub use verify_credential::*;
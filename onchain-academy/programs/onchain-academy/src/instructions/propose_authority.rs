@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::ConfigUpdated;
+use crate::state::Config;
+
+/// First step of an authority transfer. Overwrites any earlier proposal;
+/// nothing changes until `new_authority` signs `accept_authority`.
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        new_authority != Pubkey::default() && new_authority != config.authority,
+        AcademyError::InvalidAuthority
+    );
+
+    config.pending_authority = new_authority;
+
+    emit!(ConfigUpdated {
+        field: "pending_authority".to_string(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+}
@@ -9,6 +9,12 @@ pub struct RegisterMinterParams {
     pub minter: Pubkey,
     pub label: String,
     pub max_xp_per_call: u64,
+    /// Rate-limit window length in seconds. 0 = no window budget.
+    pub window_seconds: i64,
+    /// XP budget per window. 0 = unlimited; requires `window_seconds > 0` otherwise.
+    pub max_xp_per_window: u64,
+    /// Lifetime XP cap. 0 = unlimited.
+    pub max_total_xp: u64,
 }
 
 pub fn handler(ctx: Context<RegisterMinter>, params: RegisterMinterParams) -> Result<()> {
@@ -16,27 +22,45 @@ pub fn handler(ctx: Context<RegisterMinter>, params: RegisterMinterParams) -> Re
         params.label.len() <= MAX_LABEL_LEN,
         AcademyError::LabelTooLong
     );
+    validate_minter_limits(params.window_seconds, params.max_xp_per_window)?;
 
     let role = &mut ctx.accounts.minter_role;
+    role.schema_version = MinterRole::SCHEMA_VERSION;
     role.minter = params.minter;
     role.label = params.label.clone();
     role.max_xp_per_call = params.max_xp_per_call;
     role.total_xp_minted = 0;
     role.is_active = true;
     role.created_at = Clock::get()?.unix_timestamp;
-    role._reserved = [0u8; 8];
+    role.window_seconds = params.window_seconds;
+    role.max_xp_per_window = params.max_xp_per_window;
+    role.window_started_at = 0;
+    role.window_xp_minted = 0;
+    role.max_total_xp = params.max_total_xp;
+    role._reserved = [0u8; 32];
     role.bump = ctx.bumps.minter_role;
 
     emit!(MinterRegistered {
         minter: params.minter,
         label: params.label,
         max_xp_per_call: params.max_xp_per_call,
+        window_seconds: params.window_seconds,
+        max_xp_per_window: params.max_xp_per_window,
+        max_total_xp: params.max_total_xp,
         timestamp: role.created_at,
     });
 
     Ok(())
 }
 
+pub(crate) fn validate_minter_limits(window_seconds: i64, max_xp_per_window: u64) -> Result<()> {
+    require!(window_seconds >= 0, AcademyError::InvalidMinterWindow);
+    if max_xp_per_window > 0 {
+        require!(window_seconds > 0, AcademyError::InvalidMinterWindow);
+    }
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(params: RegisterMinterParams)]
 pub struct RegisterMinter<'info> {
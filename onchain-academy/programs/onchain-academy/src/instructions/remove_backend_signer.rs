@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::BackendSignerRemoved;
+use crate::state::{BackendSigner, Config};
+
+pub fn handler(ctx: Context<RemoveBackendSigner>) -> Result<()> {
+    emit!(BackendSignerRemoved {
+        signer: ctx.accounts.backend_signer.signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveBackendSigner<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"backend_signer", backend_signer.signer.as_ref()],
+        bump = backend_signer.bump,
+    )]
+    pub backend_signer: Account<'info, BackendSigner>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ AcademyError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::CourseAuthorRemoved;
+use crate::state::{Config, CourseAuthor};
+
+/// Courses the author owned keep `Course.author`; they become editable by
+/// the authority only until the author is re-added.
+pub fn handler(ctx: Context<RemoveCourseAuthor>) -> Result<()> {
+    emit!(CourseAuthorRemoved {
+        author: ctx.accounts.course_author.author,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveCourseAuthor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"course_author", course_author.author.as_ref()],
+        bump = course_author.bump,
+    )]
+    pub course_author: Account<'info, CourseAuthor>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ AcademyError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::LessonXpUpdated;
+use crate::state::{Config, Course, CourseLessonXp};
+
+/// Closes the per-lesson XP table; the course falls back to `xp_per_lesson`.
+pub fn handler(ctx: Context<RemoveLessonXp>) -> Result<()> {
+    let course = &mut ctx.accounts.course;
+    let now = Clock::get()?.unix_timestamp;
+
+    course.has_lesson_xp = false;
+    course.updated_at = now;
+
+    emit!(LessonXpUpdated {
+        course: course.key(),
+        has_lesson_xp: false,
+        total_lesson_xp: course.total_lesson_xp(None)?,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveLessonXp<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"lesson_xp", course.key().as_ref()],
+        bump = lesson_xp.bump,
+    )]
+    pub lesson_xp: Account<'info, CourseLessonXp>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
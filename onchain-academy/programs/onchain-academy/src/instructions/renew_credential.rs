@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::CredentialRenewed;
+use crate::state::{
+    BackendSigner, Config, Course, Enrollment, LearnerProfile, Track, TrackCredential,
+};
+use crate::utils::{self, CredentialCpi};
+
+/// Extends an expiring track credential by one validity period from now,
+/// using a completed enrollment in the track's recertification course that
+/// finished after the credential was last certified.
+pub fn handler(ctx: Context<RenewCredential>) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_not_paused(Config::PAUSE_CREDENTIALS)?;
+
+    let enrollment = &ctx.accounts.enrollment;
+    let course = &ctx.accounts.course;
+    let track = &ctx.accounts.track;
+    let profile = &ctx.accounts.learner_profile;
+    let credential = &ctx.accounts.track_credential;
+
+    require!(
+        track.has_recertification(),
+        AcademyError::RecertificationNotConfigured
+    );
+    require!(
+        course.key() == track.recertification_course,
+        AcademyError::NotRecertificationCourse
+    );
+    require!(!credential.is_revoked(), AcademyError::AlreadyRevoked);
+
+    let completed_at = enrollment
+        .completed_at
+        .ok_or(AcademyError::CourseNotFinalized)?;
+    require!(
+        completed_at > credential.certified_at(),
+        AcademyError::RecertificationTooOld
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let expires_at = track.credential_expiry(now);
+
+    let config_seeds: &[&[u8]] = &[b"config", &[ctx.accounts.config.bump]];
+    let config_info = ctx.accounts.config.to_account_info();
+    let payer_info = ctx.accounts.payer.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+    CredentialCpi {
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        asset: &ctx.accounts.credential_asset,
+        collection: &ctx.accounts.track_collection,
+        config: &config_info,
+        payer: &payer_info,
+        system_program: &system_program_info,
+        config_seeds,
+    }
+    .update(
+        track.credential_name(credential.level),
+        track.credential_uri(credential.level, ctx.accounts.learner.key),
+        utils::credential_attributes(
+            credential.track_id,
+            credential.level,
+            profile.courses_completed,
+            profile.total_xp,
+            credential.issued_at,
+            expires_at,
+        ),
+    )?;
+
+    let credential = &mut ctx.accounts.track_credential;
+    credential.expires_at = expires_at;
+    credential.renewed_at = now;
+    credential.updated_at = now;
+
+    emit!(CredentialRenewed {
+        learner: ctx.accounts.learner.key(),
+        track_id: credential.track_id,
+        credential_asset: credential.asset,
+        recertification_course: course.key(),
+        expires_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RenewCredential<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The track's recertification course
+    #[account(
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [b"enrollment", course.course_id.as_bytes(), learner.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.course == course.key() @ AcademyError::EnrollmentCourseMismatch,
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+
+    #[account(
+        seeds = [b"learner", learner.key().as_ref()],
+        bump = learner_profile.bump,
+    )]
+    pub learner_profile: Account<'info, LearnerProfile>,
+
+    #[account(
+        seeds = [b"track", track_credential.track_id.to_le_bytes().as_ref()],
+        bump = track.bump,
+    )]
+    pub track: Account<'info, Track>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"credential",
+            track_credential.track_id.to_le_bytes().as_ref(),
+            learner.key().as_ref(),
+        ],
+        bump = track_credential.bump,
+    )]
+    pub track_credential: Account<'info, TrackCredential>,
+
+    /// CHECK: Tied to enrollment and track_credential PDAs via seeds constraints.
+    pub learner: AccountInfo<'info>,
+
+    /// CHECK: Must be the asset recorded on the track credential.
+    #[account(
+        mut,
+        address = track_credential.asset @ AcademyError::CredentialAssetMismatch,
+    )]
+    pub credential_asset: AccountInfo<'info>,
+
+    /// CHECK: Metaplex Core collection for this track. Checked against the track registry.
+    #[account(
+        mut,
+        constraint = track_collection.key() == track.collection @ AcademyError::TrackCollectionMismatch,
+    )]
+    pub track_collection: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = utils::is_backend_signer(
+            &config,
+            backend_signer_role.as_deref(),
+            &backend_signer.key(),
+            BackendSigner::SCOPE_CREDENTIALS,
+        )? @ AcademyError::Unauthorized,
+    )]
+    pub backend_signer: Signer<'info>,
+
+    /// Registry entry for `backend_signer`. Omit when signing as `config.backend_signer`.
+    #[account(
+        seeds = [b"backend_signer", backend_signer.key().as_ref()],
+        bump = backend_signer_role.bump,
+    )]
+    pub backend_signer_role: Option<Account<'info, BackendSigner>>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::EnrollmentReset;
+use crate::state::{Config, Course, Enrollment};
+
+/// Starts a new attempt on an in-progress enrollment without closing it.
+/// `keep_progress` keeps the lesson bitmap and the attempt clock; otherwise
+/// both restart. Stale progress can only be cleared.
+pub fn handler(ctx: Context<ResetEnrollment>, keep_progress: bool) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_ALL)?;
+
+    let course = &ctx.accounts.course;
+    let enrollment = &mut ctx.accounts.enrollment;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(course.is_active, AcademyError::CourseNotActive);
+    require!(
+        enrollment.completed_at.is_none(),
+        AcademyError::CourseAlreadyFinalized
+    );
+    require!(
+        !keep_progress || !enrollment.progress_expired(course.progress_expires_after, now),
+        AcademyError::ProgressExpired
+    );
+
+    enrollment.attempt = enrollment
+        .attempt
+        .checked_add(1)
+        .ok_or(AcademyError::Overflow)?;
+    if !keep_progress {
+        enrollment.lesson_flags = [0u64; 4];
+        enrollment.attempt_started_at = now;
+        enrollment.course_version = course.version;
+    }
+
+    emit!(EnrollmentReset {
+        learner: ctx.accounts.learner.key(),
+        course: course.key(),
+        attempt: enrollment.attempt,
+        kept_progress: keep_progress,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResetEnrollment<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        mut,
+        seeds = [b"enrollment", course.course_id.as_bytes(), learner.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.course == course.key() @ AcademyError::EnrollmentCourseMismatch,
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+
+    pub learner: Signer<'info>,
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::CourseRestructured;
+use crate::state::{Config, Course, LessonMap};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RestructureCourseParams {
+    pub new_content_tx_id: [u8; 32],
+    pub new_lesson_count: u8,
+    /// New index for each current lesson (length = current `lesson_count`);
+    /// `LessonMap::REMOVED` drops the lesson
+    pub mapping: Vec<u8>,
+}
+
+/// Adds, removes or reorders lessons. Bumps `course.version`, publishes a
+/// `LessonMap` from the previous layout, and requires in-flight enrollments to
+/// be remapped with `migrate_enrollment_version` before they progress.
+pub fn handler(ctx: Context<RestructureCourse>, params: RestructureCourseParams) -> Result<()> {
+    let course_key = ctx.accounts.course.key();
+    let course = &mut ctx.accounts.course;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        params.new_lesson_count > 0,
+        AcademyError::InvalidLessonCount
+    );
+    require!(!course.has_lesson_xp, AcademyError::LessonXpTablePresent);
+    require!(
+        params.mapping.len() == course.lesson_count as usize
+            && LessonMap::is_valid_mapping(&params.mapping, params.new_lesson_count),
+        AcademyError::InvalidLessonMap
+    );
+
+    let from_version = course.lesson_layout_version;
+    let to_version = course
+        .version
+        .checked_add(1)
+        .ok_or(AcademyError::Overflow)?;
+    let old_lesson_count = course.lesson_count;
+
+    let lesson_map = &mut ctx.accounts.lesson_map;
+    lesson_map.schema_version = LessonMap::SCHEMA_VERSION;
+    lesson_map.course = course_key;
+    lesson_map.from_version = from_version;
+    lesson_map.to_version = to_version;
+    lesson_map.new_lesson_count = params.new_lesson_count;
+    lesson_map.mapping = params.mapping;
+    lesson_map._reserved = [0u8; 16];
+    lesson_map.bump = ctx.bumps.lesson_map;
+
+    course.content_tx_id = params.new_content_tx_id;
+    course.version = to_version;
+    course.lesson_count = params.new_lesson_count;
+    course.lesson_layout_version = to_version;
+    course.updated_at = now;
+
+    emit!(CourseRestructured {
+        course: course_key,
+        from_version,
+        to_version,
+        old_lesson_count,
+        new_lesson_count: params.new_lesson_count,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: RestructureCourseParams)]
+pub struct RestructureCourse<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = LessonMap::space(course.lesson_count),
+        seeds = [
+            b"lesson_map",
+            course.key().as_ref(),
+            &course.version.checked_add(1).ok_or(AcademyError::Overflow)?.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub lesson_map: Account<'info, LessonMap>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
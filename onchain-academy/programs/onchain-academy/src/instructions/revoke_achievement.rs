@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::AchievementRevoked;
+use crate::state::{AchievementReceipt, AchievementType, Config, MAX_REVOCATION_REASON_LEN};
+use crate::utils::CredentialCpi;
+
+/// Marks the achievement NFT revoked and closes its receipt, which removes it
+/// as a prerequisite proof and lets the achievement be awarded again. Counts
+/// towards `revoked_count`, freeing one unit of supply without reusing its
+/// `supply_number`; XP minted with the award is not clawed back.
+pub fn handler(ctx: Context<RevokeAchievement>, reason: String) -> Result<()> {
+    require!(
+        !reason.is_empty() && reason.len() <= MAX_REVOCATION_REASON_LEN,
+        AcademyError::InvalidRevocationReason
+    );
+
+    let config_seeds: &[&[u8]] = &[b"config", &[ctx.accounts.config.bump]];
+    let config_info = ctx.accounts.config.to_account_info();
+    let authority_info = ctx.accounts.authority.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+    CredentialCpi {
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        asset: &ctx.accounts.asset,
+        collection: &ctx.accounts.collection,
+        config: &config_info,
+        payer: &authority_info,
+        system_program: &system_program_info,
+        config_seeds,
+    }
+    .revoke(&reason)?;
+
+    let achievement = &mut ctx.accounts.achievement_type;
+    achievement.revoked_count = achievement
+        .revoked_count
+        .checked_add(1)
+        .ok_or(AcademyError::Overflow)?;
+
+    emit!(AchievementRevoked {
+        achievement_id: ctx.accounts.achievement_type.achievement_id.clone(),
+        recipient: ctx.accounts.recipient.key(),
+        asset: ctx.accounts.asset.key(),
+        reason,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeAchievement<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"achievement", achievement_type.achievement_id.as_bytes()],
+        bump = achievement_type.bump,
+    )]
+    pub achievement_type: Account<'info, AchievementType>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"achievement_receipt",
+            achievement_type.achievement_id.as_bytes(),
+            recipient.key().as_ref(),
+        ],
+        bump = achievement_receipt.bump,
+    )]
+    pub achievement_receipt: Account<'info, AchievementReceipt>,
+
+    /// CHECK: Tied to achievement_receipt PDA via seeds constraint.
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Must be the asset recorded on the receipt.
+    #[account(
+        mut,
+        address = achievement_receipt.asset @ AcademyError::CredentialAssetMismatch,
+    )]
+    pub asset: AccountInfo<'info>,
+
+    /// CHECK: Metaplex Core collection for this achievement.
+    #[account(
+        mut,
+        constraint = collection.key() == achievement_type.collection @ AcademyError::Unauthorized,
+    )]
+    pub collection: AccountInfo<'info>,
+
+    /// Pays for the attribute update and receives the receipt's rent
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ AcademyError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::CredentialRevoked;
+use crate::state::{Config, Course, Enrollment, TrackCredential, MAX_REVOCATION_REASON_LEN};
+use crate::utils::CredentialCpi;
+
+/// Marks the learner's track credential revoked and clears the credential
+/// pointer on the enrollments passed as `[Course, Enrollment]` pairs in
+/// remaining accounts, so they can be issued into a fresh asset later.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RevokeCredential<'info>>,
+    reason: String,
+) -> Result<()> {
+    require!(
+        !reason.is_empty() && reason.len() <= MAX_REVOCATION_REASON_LEN,
+        AcademyError::InvalidRevocationReason
+    );
+    require!(
+        !ctx.accounts.track_credential.is_revoked(),
+        AcademyError::AlreadyRevoked
+    );
+
+    let config_seeds: &[&[u8]] = &[b"config", &[ctx.accounts.config.bump]];
+    let config_info = ctx.accounts.config.to_account_info();
+    let authority_info = ctx.accounts.authority.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+    CredentialCpi {
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        asset: &ctx.accounts.credential_asset,
+        collection: &ctx.accounts.collection,
+        config: &config_info,
+        payer: &authority_info,
+        system_program: &system_program_info,
+        config_seeds,
+    }
+    .revoke(&reason)?;
+
+    let learner = ctx.accounts.learner.key();
+    let asset = ctx.accounts.credential_asset.key();
+    let remaining = ctx.remaining_accounts;
+    require!(
+        remaining.chunks_exact(2).remainder().is_empty(),
+        AcademyError::InvalidRevocationAccounts
+    );
+    for pair in remaining.chunks(2) {
+        let course = Account::<Course>::try_from(&pair[0])
+            .map_err(|_| AcademyError::InvalidRevocationAccounts)?;
+        let mut enrollment = Account::<Enrollment>::try_from(&pair[1])
+            .map_err(|_| AcademyError::InvalidRevocationAccounts)?;
+        let (expected_pda, _) = Pubkey::find_program_address(
+            &[b"enrollment", course.course_id.as_bytes(), learner.as_ref()],
+            ctx.program_id,
+        );
+        require!(
+            pair[1].key() == expected_pda
+                && enrollment.course == course.key()
+                && enrollment.credential_asset == Some(asset),
+            AcademyError::InvalidRevocationAccounts
+        );
+        enrollment.credential_asset = None;
+        enrollment.exit(ctx.program_id)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &mut ctx.accounts.track_credential;
+    credential.revoked_at = now;
+    credential.updated_at = now;
+
+    emit!(CredentialRevoked {
+        learner,
+        track_id: credential.track_id,
+        credential_asset: asset,
+        reason,
+        enrollments_cleared: (remaining.len() / 2) as u8,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"credential",
+            track_credential.track_id.to_le_bytes().as_ref(),
+            learner.key().as_ref(),
+        ],
+        bump = track_credential.bump,
+    )]
+    pub track_credential: Account<'info, TrackCredential>,
+
+    /// CHECK: Tied to track_credential PDA via seeds constraint.
+    pub learner: AccountInfo<'info>,
+
+    /// CHECK: Must be the asset recorded on the track credential.
+    #[account(
+        mut,
+        address = track_credential.asset @ AcademyError::CredentialAssetMismatch,
+    )]
+    pub credential_asset: AccountInfo<'info>,
+
+    /// CHECK: The asset's Metaplex Core collection. Validated by CPI.
+    #[account(mut)]
+    pub collection: AccountInfo<'info>,
+
+    /// Pays for the attribute update
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ AcademyError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Metaplex Core program.
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -2,10 +2,12 @@ use anchor_lang::prelude::*;
 
 use crate::errors::AcademyError;
 use crate::events::XpRewarded;
-use crate::state::{Config, MinterRole};
-use crate::utils::mint_xp;
+use crate::state::{Config, LearnerProfile, MinterRole};
+use crate::utils::{mint_xp, require_xp_token_account};
 
 pub fn handler(ctx: Context<RewardXp>, amount: u64, memo: String) -> Result<()> {
+    ctx.accounts.config.require_not_paused(Config::PAUSE_XP)?;
+
     let role = &ctx.accounts.minter_role;
 
     require!(role.is_active, AcademyError::MinterNotActive);
@@ -19,6 +21,24 @@ pub fn handler(ctx: Context<RewardXp>, amount: u64, memo: String) -> Result<()>
     }
 
     let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+
+    require_xp_token_account(
+        &ctx.accounts.recipient_token_account,
+        &config.xp_mint,
+        &ctx.accounts.recipient.key(),
+    )?;
+
+    ctx.accounts.minter_role.record_mint(amount, now)?;
+
+    let profile = &mut ctx.accounts.recipient_profile;
+    profile.init_if_new(
+        ctx.accounts.recipient.key(),
+        now,
+        ctx.bumps.recipient_profile,
+    );
+    profile.record_xp(amount, now, config.daily_xp_cap)?;
+
     let config_seeds: &[&[u8]] = &[b"config", &[config.bump]];
 
     mint_xp(
@@ -30,18 +50,12 @@ pub fn handler(ctx: Context<RewardXp>, amount: u64, memo: String) -> Result<()>
         amount,
     )?;
 
-    let role_mut = &mut ctx.accounts.minter_role;
-    role_mut.total_xp_minted = role_mut
-        .total_xp_minted
-        .checked_add(amount)
-        .ok_or(AcademyError::Overflow)?;
-
     emit!(XpRewarded {
         minter: ctx.accounts.minter.key(),
         recipient: ctx.accounts.recipient_token_account.key(),
         amount,
         memo,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
     Ok(())
@@ -71,14 +85,32 @@ pub struct RewardXp<'info> {
     )]
     pub xp_mint: AccountInfo<'info>,
 
+    /// CHECK: Recipient wallet. Only used to derive the learner profile.
+    pub recipient: AccountInfo<'info>,
+
+    /// Created here if the recipient has never enrolled
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LearnerProfile::SIZE,
+        seeds = [b"learner", recipient.key().as_ref()],
+        bump,
+    )]
+    pub recipient_profile: Account<'info, LearnerProfile>,
+
     /// Recipient's Token-2022 ATA for XP
-    /// CHECK: Validated by Token-2022 CPI.
+    /// CHECK: Mint and owner validated in handler.
     #[account(mut)]
     pub recipient_token_account: AccountInfo<'info>,
 
     pub minter: Signer<'info>,
 
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     /// CHECK: Validated by address constraint.
     #[account(address = spl_token_2022::id())]
     pub token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
 }
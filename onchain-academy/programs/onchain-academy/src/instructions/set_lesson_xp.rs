@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::LessonXpUpdated;
+use crate::state::{Config, Course, CourseLessonXp};
+
+/// Creates or overwrites the course's per-lesson XP table. From then on
+/// lesson completions award `lesson_xp[i]` instead of `xp_per_lesson`.
+pub fn handler(ctx: Context<SetLessonXp>, lesson_xp: Vec<u32>) -> Result<()> {
+    let course = &mut ctx.accounts.course;
+
+    require!(
+        lesson_xp.len() == course.lesson_count as usize,
+        AcademyError::LessonXpLengthMismatch
+    );
+
+    let table = &mut ctx.accounts.lesson_xp;
+    table.schema_version = CourseLessonXp::SCHEMA_VERSION;
+    table.course = course.key();
+    table.lesson_xp = lesson_xp;
+    table._reserved = [0u8; 16];
+    table.bump = ctx.bumps.lesson_xp;
+
+    let now = Clock::get()?.unix_timestamp;
+    course.has_lesson_xp = true;
+    course.updated_at = now;
+
+    emit!(LessonXpUpdated {
+        course: course.key(),
+        has_lesson_xp: true,
+        total_lesson_xp: course.total_lesson_xp(Some(table))?,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLessonXp<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"course", course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CourseLessonXp::space(course.lesson_count),
+        seeds = [b"lesson_xp", course.key().as_ref()],
+        bump,
+    )]
+    pub lesson_xp: Account<'info, CourseLessonXp>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::PauseToggled;
+use crate::state::Config;
+
+/// Replaces the pause flag set. The authority may set any combination; the
+/// guardian may only add flags, so a compromised guardian cannot unpause.
+pub fn handler(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+
+    require!(
+        paused & !Config::PAUSE_FLAGS == 0,
+        AcademyError::InvalidPauseFlags
+    );
+    if signer != config.authority {
+        require!(
+            paused & config.paused == config.paused,
+            AcademyError::Unauthorized
+        );
+    }
+
+    let previous = config.paused;
+    config.paused = paused;
+
+    emit!(PauseToggled {
+        previous,
+        paused,
+        by: signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = signer.key() == config.authority
+            || (config.guardian != Pubkey::default() && signer.key() == config.guardian)
+            @ AcademyError::Unauthorized,
+    )]
+    pub signer: Signer<'info>,
+}
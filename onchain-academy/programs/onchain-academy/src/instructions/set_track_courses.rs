@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::TrackCoursesSet;
+use crate::state::{Config, Course, Track, MAX_COURSES_PER_LEVEL};
+
+/// Replaces the ordered course list for `level`. Each listed Course PDA must
+/// be passed in `remaining_accounts`, in the same order, and carry the
+/// track's `track_id` and `track_level == level`. An empty list clears it.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetTrackCourses<'info>>,
+    level: u8,
+    courses: Vec<Pubkey>,
+) -> Result<()> {
+    let track = &mut ctx.accounts.track;
+
+    require!(track.has_level(level), AcademyError::InvalidTrackCourses);
+    require!(
+        courses.len() <= MAX_COURSES_PER_LEVEL && courses.len() == ctx.remaining_accounts.len(),
+        AcademyError::InvalidTrackCourses
+    );
+
+    for (i, (key, course_info)) in courses.iter().zip(ctx.remaining_accounts).enumerate() {
+        require!(
+            course_info.key() == *key && !courses[..i].contains(key),
+            AcademyError::InvalidTrackCourses
+        );
+        let course = Account::<Course>::try_from(course_info)
+            .map_err(|_| AcademyError::InvalidTrackCourses)?;
+        require!(
+            course.track_id == track.track_id && course.track_level == level,
+            AcademyError::InvalidTrackCourses
+        );
+        // A course belongs to one level only
+        require!(
+            track.level_of(key).unwrap_or(level) == level,
+            AcademyError::InvalidTrackCourses
+        );
+    }
+
+    let index = level as usize - 1;
+    if track.level_courses.len() <= index {
+        track.level_courses.resize(index + 1, Vec::new());
+    }
+    track.level_courses[index] = courses.clone();
+    while track
+        .level_courses
+        .last()
+        .is_some_and(|courses| courses.is_empty())
+    {
+        track.level_courses.pop();
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    track.updated_at = now;
+
+    emit!(TrackCoursesSet {
+        track_id: track.track_id,
+        level,
+        courses,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTrackCourses<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"track", track.track_id.to_le_bytes().as_ref()],
+        bump = track.bump,
+    )]
+    pub track: Account<'info, Track>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::CohortUpdated;
+use crate::state::{validate_schedule, Cohort, Config};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateCohortParams {
+    pub new_enroll_opens_at: Option<i64>,
+    pub new_enroll_closes_at: Option<i64>,
+    pub new_completion_deadline: Option<i64>,
+    pub new_max_enrollments: Option<u32>,
+    pub new_is_active: Option<bool>,
+}
+
+pub fn handler(ctx: Context<UpdateCohort>, params: UpdateCohortParams) -> Result<()> {
+    let cohort = &mut ctx.accounts.cohort;
+
+    if let Some(enroll_opens_at) = params.new_enroll_opens_at {
+        cohort.enroll_opens_at = enroll_opens_at;
+    }
+
+    if let Some(enroll_closes_at) = params.new_enroll_closes_at {
+        cohort.enroll_closes_at = enroll_closes_at;
+    }
+
+    if let Some(completion_deadline) = params.new_completion_deadline {
+        cohort.completion_deadline = completion_deadline;
+    }
+
+    validate_schedule(
+        cohort.enroll_opens_at,
+        cohort.enroll_closes_at,
+        cohort.completion_deadline,
+    )?;
+
+    if let Some(max_enrollments) = params.new_max_enrollments {
+        cohort.max_enrollments = max_enrollments;
+    }
+
+    if let Some(is_active) = params.new_is_active {
+        cohort.is_active = is_active;
+    }
+
+    emit!(CohortUpdated {
+        cohort: cohort.key(),
+        course: cohort.course,
+        enroll_opens_at: cohort.enroll_opens_at,
+        enroll_closes_at: cohort.enroll_closes_at,
+        completion_deadline: cohort.completion_deadline,
+        max_enrollments: cohort.max_enrollments,
+        is_active: cohort.is_active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCohort<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"cohort", cohort.course.as_ref(), &cohort.cohort_id.to_le_bytes()],
+        bump = cohort.bump,
+    )]
+    pub cohort: Account<'info, Cohort>,
+
+    pub authority: Signer<'info>,
+}
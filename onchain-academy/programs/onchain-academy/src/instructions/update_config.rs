@@ -7,6 +7,11 @@ use crate::state::{Config, MinterRole};
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateConfigParams {
     pub new_backend_signer: Option<Pubkey>,
+    /// Per-learner daily XP ceiling. 0 disables the cap. Must cover the
+    /// largest lesson XP and completion bonus, which fail rather than clamp.
+    pub new_daily_xp_cap: Option<u64>,
+    /// Key allowed to pause the program. `Pubkey::default()` removes it.
+    pub new_guardian: Option<Pubkey>,
 }
 
 pub fn handler<'info>(
@@ -41,6 +46,22 @@ pub fn handler<'info>(
         });
     }
 
+    if let Some(daily_xp_cap) = params.new_daily_xp_cap {
+        config.daily_xp_cap = daily_xp_cap;
+        emit!(ConfigUpdated {
+            field: "daily_xp_cap".to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    if let Some(guardian) = params.new_guardian {
+        config.guardian = guardian;
+        emit!(ConfigUpdated {
+            field: "guardian".to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
     Ok(())
 }
 
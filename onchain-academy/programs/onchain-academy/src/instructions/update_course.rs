@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::AcademyError;
-use crate::events::CourseUpdated;
-use crate::state::{Config, Course};
+use crate::events::{CourseChange, CourseUpdated};
+use crate::state::{validate_schedule, Config, Course, CourseAuthor, MAX_BASIS_POINTS};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateCourseParams {
@@ -11,54 +11,288 @@ pub struct UpdateCourseParams {
     pub new_xp_per_lesson: Option<u32>,
     pub new_creator_reward_xp: Option<u32>,
     pub new_min_completions_for_reward: Option<u16>,
+    pub new_completion_bonus_xp: Option<u32>,
+    pub new_completion_bonus_bps: Option<u16>,
+    /// Replaces the whole prerequisite set together with its min-count
+    pub new_prerequisites: Option<Vec<Pubkey>>,
+    pub new_prerequisite_min_count: Option<u8>,
+    pub new_enroll_opens_at: Option<i64>,
+    pub new_enroll_closes_at: Option<i64>,
+    pub new_completion_deadline: Option<i64>,
+    pub new_max_enrollments: Option<u32>,
+    pub new_cohort_only: Option<bool>,
+    pub new_progress_expires_after: Option<i64>,
+    /// Appending lessons is always allowed; removing them needs
+    /// `restructure_course` once anyone has enrolled
+    pub new_lesson_count: Option<u8>,
+    pub new_difficulty: Option<u8>,
+    pub new_track_id: Option<u16>,
+    pub new_track_level: Option<u8>,
+    pub new_creator: Option<Pubkey>,
+    /// Hands the course to a `CourseAuthor`; `Pubkey::default()` takes it back
+    pub new_author: Option<Pubkey>,
 }
 
+impl UpdateCourseParams {
+    /// Whether the update only touches content and learner XP, the fields a
+    /// course author may edit on its own courses.
+    pub fn author_editable(&self) -> bool {
+        self.new_is_active.is_none()
+            && self.new_creator_reward_xp.is_none()
+            && self.new_min_completions_for_reward.is_none()
+            && self.new_prerequisites.is_none()
+            && self.new_prerequisite_min_count.is_none()
+            && self.new_enroll_opens_at.is_none()
+            && self.new_enroll_closes_at.is_none()
+            && self.new_completion_deadline.is_none()
+            && self.new_max_enrollments.is_none()
+            && self.new_cohort_only.is_none()
+            && self.new_progress_expires_after.is_none()
+            && self.new_lesson_count.is_none()
+            && self.new_difficulty.is_none()
+            && self.new_track_id.is_none()
+            && self.new_track_level.is_none()
+            && self.new_creator.is_none()
+            && self.new_author.is_none()
+    }
+}
+
+/// The authority may change any field. The course's author may change
+/// content and learner XP while it remains assigned to the course's track.
 pub fn handler(ctx: Context<UpdateCourse>, params: UpdateCourseParams) -> Result<()> {
     let course_key = ctx.accounts.course.key();
+    let signer = ctx.accounts.authority.key();
+    if signer != ctx.accounts.config.authority {
+        ctx.accounts.config.require_not_paused(Config::PAUSE_ALL)?;
+        let course_author = ctx
+            .accounts
+            .course_author
+            .as_deref()
+            .ok_or(AcademyError::Unauthorized)?;
+        require!(
+            ctx.accounts.course.author == signer,
+            AcademyError::Unauthorized
+        );
+        require!(
+            course_author.allows_track(ctx.accounts.course.track_id),
+            AcademyError::TrackNotAssigned
+        );
+        require!(params.author_editable(), AcademyError::AuthorityRequired);
+    }
+
     let course = &mut ctx.accounts.course;
     let now = Clock::get()?.unix_timestamp;
+    let mut changes = Vec::new();
 
     if let Some(content_tx_id) = params.new_content_tx_id {
-        course.content_tx_id = content_tx_id;
+        update(
+            &mut course.content_tx_id,
+            Some(content_tx_id),
+            &mut changes,
+            |old, new| CourseChange::ContentTxId { old, new },
+        );
         course.version = course
             .version
             .checked_add(1)
             .ok_or(AcademyError::Overflow)?;
     }
 
-    if let Some(is_active) = params.new_is_active {
-        course.is_active = is_active;
+    update(
+        &mut course.is_active,
+        params.new_is_active,
+        &mut changes,
+        |old, new| CourseChange::IsActive { old, new },
+    );
+    update(
+        &mut course.xp_per_lesson,
+        params.new_xp_per_lesson,
+        &mut changes,
+        |old, new| CourseChange::XpPerLesson { old, new },
+    );
+    update(
+        &mut course.creator_reward_xp,
+        params.new_creator_reward_xp,
+        &mut changes,
+        |old, new| CourseChange::CreatorRewardXp { old, new },
+    );
+    update(
+        &mut course.min_completions_for_reward,
+        params.new_min_completions_for_reward,
+        &mut changes,
+        |old, new| CourseChange::MinCompletionsForReward { old, new },
+    );
+    update(
+        &mut course.completion_bonus_xp,
+        params.new_completion_bonus_xp,
+        &mut changes,
+        |old, new| CourseChange::CompletionBonusXp { old, new },
+    );
+    if let Some(bps) = params.new_completion_bonus_bps {
+        require!(
+            bps <= MAX_BASIS_POINTS,
+            AcademyError::InvalidCompletionBonus
+        );
+    }
+    update(
+        &mut course.completion_bonus_bps,
+        params.new_completion_bonus_bps,
+        &mut changes,
+        |old, new| CourseChange::CompletionBonusBps { old, new },
+    );
+
+    if params.new_prerequisites.is_some() || params.new_prerequisite_min_count.is_some() {
+        let old_prerequisites = course.prerequisites();
+        let old_min_count = course.prerequisite_min_count;
+        let prerequisites = params
+            .new_prerequisites
+            .unwrap_or_else(|| old_prerequisites.clone());
+        let min_count = params.new_prerequisite_min_count.unwrap_or(old_min_count);
+        require!(
+            !prerequisites.contains(&course_key),
+            AcademyError::InvalidPrerequisites
+        );
+        course.set_prerequisites(&prerequisites, min_count)?;
+        if prerequisites != old_prerequisites {
+            changes.push(CourseChange::Prerequisites {
+                old: old_prerequisites,
+                new: prerequisites,
+            });
+        }
+        if min_count != old_min_count {
+            changes.push(CourseChange::PrerequisiteMinCount {
+                old: old_min_count,
+                new: min_count,
+            });
+        }
     }
 
-    if let Some(xp_per_lesson) = params.new_xp_per_lesson {
-        course.xp_per_lesson = xp_per_lesson;
+    update(
+        &mut course.enroll_opens_at,
+        params.new_enroll_opens_at,
+        &mut changes,
+        |old, new| CourseChange::EnrollOpensAt { old, new },
+    );
+    update(
+        &mut course.enroll_closes_at,
+        params.new_enroll_closes_at,
+        &mut changes,
+        |old, new| CourseChange::EnrollClosesAt { old, new },
+    );
+    update(
+        &mut course.completion_deadline,
+        params.new_completion_deadline,
+        &mut changes,
+        |old, new| CourseChange::CompletionDeadline { old, new },
+    );
+
+    validate_schedule(
+        course.enroll_opens_at,
+        course.enroll_closes_at,
+        course.completion_deadline,
+    )?;
+
+    update(
+        &mut course.max_enrollments,
+        params.new_max_enrollments,
+        &mut changes,
+        |old, new| CourseChange::MaxEnrollments { old, new },
+    );
+    update(
+        &mut course.cohort_only,
+        params.new_cohort_only,
+        &mut changes,
+        |old, new| CourseChange::CohortOnly { old, new },
+    );
+
+    if let Some(progress_expires_after) = params.new_progress_expires_after {
+        require!(progress_expires_after >= 0, AcademyError::InvalidSchedule);
     }
+    update(
+        &mut course.progress_expires_after,
+        params.new_progress_expires_after,
+        &mut changes,
+        |old, new| CourseChange::ProgressExpiresAfter { old, new },
+    );
 
-    if let Some(creator_reward_xp) = params.new_creator_reward_xp {
-        course.creator_reward_xp = creator_reward_xp;
+    if let Some(lesson_count) = params.new_lesson_count {
+        course.validate_lesson_count_update(lesson_count)?;
     }
+    update(
+        &mut course.lesson_count,
+        params.new_lesson_count,
+        &mut changes,
+        |old, new| CourseChange::LessonCount { old, new },
+    );
 
-    if let Some(min_completions) = params.new_min_completions_for_reward {
-        course.min_completions_for_reward = min_completions;
+    if let Some(difficulty) = params.new_difficulty {
+        require!(
+            (1..=3).contains(&difficulty),
+            AcademyError::InvalidDifficulty
+        );
     }
+    update(
+        &mut course.difficulty,
+        params.new_difficulty,
+        &mut changes,
+        |old, new| CourseChange::Difficulty { old, new },
+    );
+    update(
+        &mut course.track_id,
+        params.new_track_id,
+        &mut changes,
+        |old, new| CourseChange::TrackId { old, new },
+    );
+    update(
+        &mut course.track_level,
+        params.new_track_level,
+        &mut changes,
+        |old, new| CourseChange::TrackLevel { old, new },
+    );
+    update(
+        &mut course.creator,
+        params.new_creator,
+        &mut changes,
+        |old, new| CourseChange::Creator { old, new },
+    );
+    update(
+        &mut course.author,
+        params.new_author,
+        &mut changes,
+        |old, new| CourseChange::Author { old, new },
+    );
 
     course.updated_at = now;
 
     emit!(CourseUpdated {
         course: course_key,
         version: course.version,
+        changes,
         timestamp: now,
     });
 
     Ok(())
 }
 
+/// Writes `new` into `field` if given, recording a change when the value differs.
+fn update<T: Copy + PartialEq>(
+    field: &mut T,
+    new: Option<T>,
+    changes: &mut Vec<CourseChange>,
+    change: fn(T, T) -> CourseChange,
+) {
+    if let Some(new) = new {
+        if *field != new {
+            changes.push(change(*field, new));
+            *field = new;
+        }
+    }
+}
+
 #[derive(Accounts)]
 pub struct UpdateCourse<'info> {
     #[account(
         seeds = [b"config"],
         bump = config.bump,
-        has_one = authority @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
 
@@ -69,5 +303,13 @@ pub struct UpdateCourse<'info> {
     )]
     pub course: Account<'info, Course>,
 
+    /// `config.authority`, or the course's author
     pub authority: Signer<'info>,
+
+    /// Registry entry for `authority` when signing as a course author
+    #[account(
+        seeds = [b"course_author", authority.key().as_ref()],
+        bump = course_author.bump,
+    )]
+    pub course_author: Option<Account<'info, CourseAuthor>>,
 }
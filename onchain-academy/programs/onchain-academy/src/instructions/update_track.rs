@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+use crate::events::TrackUpdated;
+use crate::state::{Config, Track, MAX_TRACK_LEVELS, MAX_TRACK_NAME_LEN, MAX_TRACK_URI_LEN};
+use crate::utils;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateTrackParams {
+    pub new_name: Option<String>,
+    /// Applies to credentials issued or upgraded from now on
+    pub new_uri_template: Option<String>,
+    /// Cannot drop below the highest level that still lists courses
+    pub new_max_level: Option<u8>,
+    /// Switch to the passed `collection` account. Existing credentials stay
+    /// in the old collection and can no longer be upgraded.
+    pub new_collection: bool,
+    /// Applies to credentials issued or renewed from now on
+    pub new_validity_period: Option<i64>,
+    /// Pass `Pubkey::default()` to remove the recertification course
+    pub new_recertification_course: Option<Pubkey>,
+}
+
+pub fn handler(ctx: Context<UpdateTrack>, params: UpdateTrackParams) -> Result<()> {
+    let config_key = ctx.accounts.config.key();
+    let track = &mut ctx.accounts.track;
+
+    if let Some(name) = params.new_name {
+        require!(
+            !name.is_empty() && name.len() <= MAX_TRACK_NAME_LEN,
+            AcademyError::InvalidTrack
+        );
+        track.name = name;
+    }
+
+    if let Some(uri_template) = params.new_uri_template {
+        require!(
+            !uri_template.is_empty() && uri_template.len() <= MAX_TRACK_URI_LEN,
+            AcademyError::InvalidTrack
+        );
+        track.uri_template = uri_template;
+    }
+
+    if let Some(max_level) = params.new_max_level {
+        require!(
+            (1..=MAX_TRACK_LEVELS as u8).contains(&max_level),
+            AcademyError::InvalidTrack
+        );
+        require!(
+            track.level_courses.len() <= max_level as usize,
+            AcademyError::InvalidTrack
+        );
+        track.max_level = max_level;
+    }
+
+    if let Some(validity_period) = params.new_validity_period {
+        require!(validity_period >= 0, AcademyError::InvalidTrack);
+        track.validity_period = validity_period;
+    }
+
+    if let Some(recertification_course) = params.new_recertification_course {
+        track.recertification_course = recertification_course;
+    }
+
+    if params.new_collection {
+        let collection = ctx
+            .accounts
+            .collection
+            .as_ref()
+            .ok_or(AcademyError::InvalidTrack)?;
+        utils::require_academy_collection(collection, &config_key)?;
+        track.collection = collection.key();
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    track.updated_at = now;
+
+    emit!(TrackUpdated {
+        track_id: track.track_id,
+        collection: track.collection,
+        max_level: track.max_level,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateTrack<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"track", track.track_id.to_le_bytes().as_ref()],
+        bump = track.bump,
+    )]
+    pub track: Account<'info, Track>,
+
+    /// CHECK: New Metaplex Core collection. Required when `new_collection`;
+    /// owner and update authority checked in handler.
+    pub collection: Option<AccountInfo<'info>>,
+
+    pub authority: Signer<'info>,
+}
@@ -1,23 +1,25 @@
 use anchor_lang::prelude::*;
-use mpl_core::{
-    instructions::{UpdatePluginV1CpiBuilder, UpdateV1CpiBuilder},
-    types::{Attribute, Attributes, Plugin},
-};
 
 use crate::errors::AcademyError;
 use crate::events::CredentialUpgraded;
-use crate::state::{Config, Course, Enrollment};
-
-pub fn handler(
-    ctx: Context<UpgradeCredential>,
-    credential_name: String,
-    metadata_uri: String,
-    courses_completed: u32,
-    total_xp: u64,
-) -> Result<()> {
+use crate::state::{
+    BackendSigner, Config, Course, Enrollment, LearnerProfile, Track, TrackCredential,
+};
+use crate::utils::{self, CredentialCpi};
+
+/// Re-applies an already credited enrollment to the learner's track credential.
+/// Credentials minted before `TrackCredential` existed are adopted here: the
+/// first call on a track records the enrollment's asset as the track credential.
+pub fn handler(ctx: Context<UpgradeCredential>) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_not_paused(Config::PAUSE_CREDENTIALS)?;
+
     let enrollment = &ctx.accounts.enrollment;
     let course = &ctx.accounts.course;
+    let track = &ctx.accounts.track;
     let config = &ctx.accounts.config;
+    let profile = &ctx.accounts.learner_profile;
 
     require!(
         enrollment.completed_at.is_some(),
@@ -28,59 +30,97 @@ pub fn handler(
         .credential_asset
         .ok_or(AcademyError::CourseNotFinalized)?;
 
+    let credential = &ctx.accounts.track_credential;
+    require!(!credential.is_revoked(), AcademyError::AlreadyRevoked);
+    let adopted = credential.is_new();
+    if !adopted {
+        require!(
+            existing_asset == credential.asset,
+            AcademyError::CredentialAssetMismatch
+        );
+    }
     require!(
         ctx.accounts.credential_asset.key() == existing_asset,
         AcademyError::CredentialAssetMismatch
     );
+    require!(
+        track.level_of(&course.key()) == Some(course.track_level),
+        AcademyError::CourseNotInTrack
+    );
+
+    let (asset_track_id, asset_level, _) = utils::read_credential_track(
+        &ctx.accounts.credential_asset,
+        &ctx.accounts.track_collection,
+        &config.key(),
+        &ctx.accounts.learner.key(),
+    )?;
+    require!(
+        asset_track_id == course.track_id,
+        AcademyError::CredentialAssetMismatch
+    );
+    require!(
+        course.track_level >= asset_level,
+        AcademyError::CredentialDowngrade
+    );
+
+    // Adopted credentials start their validity period now
+    let now = Clock::get()?.unix_timestamp;
+    let (issued_at, expires_at) = if adopted {
+        (now, track.credential_expiry(now))
+    } else {
+        (credential.issued_at, credential.expires_at)
+    };
 
     let config_bump = config.bump;
     let config_seeds: &[&[u8]] = &[b"config", &[config_bump]];
-    let signer_seeds = &[config_seeds];
-
-    UpdateV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
-        .asset(&ctx.accounts.credential_asset)
-        .collection(Some(&ctx.accounts.track_collection))
-        .authority(Some(&ctx.accounts.config.to_account_info()))
-        .payer(&ctx.accounts.payer.to_account_info())
-        .system_program(&ctx.accounts.system_program.to_account_info())
-        .new_name(credential_name)
-        .new_uri(metadata_uri)
-        .invoke_signed(signer_seeds)?;
-
-    UpdatePluginV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
-        .asset(&ctx.accounts.credential_asset)
-        .collection(Some(&ctx.accounts.track_collection))
-        .authority(Some(&ctx.accounts.config.to_account_info()))
-        .payer(&ctx.accounts.payer.to_account_info())
-        .system_program(&ctx.accounts.system_program.to_account_info())
-        .plugin(Plugin::Attributes(Attributes {
-            attribute_list: vec![
-                Attribute {
-                    key: "track_id".into(),
-                    value: course.track_id.to_string(),
-                },
-                Attribute {
-                    key: "level".into(),
-                    value: course.track_level.to_string(),
-                },
-                Attribute {
-                    key: "courses_completed".into(),
-                    value: courses_completed.to_string(),
-                },
-                Attribute {
-                    key: "total_xp".into(),
-                    value: total_xp.to_string(),
-                },
-            ],
-        }))
-        .invoke_signed(signer_seeds)?;
+    let config_info = config.to_account_info();
+    let payer_info = ctx.accounts.payer.to_account_info();
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+    CredentialCpi {
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        asset: &ctx.accounts.credential_asset,
+        collection: &ctx.accounts.track_collection,
+        config: &config_info,
+        payer: &payer_info,
+        system_program: &system_program_info,
+        config_seeds,
+    }
+    .update(
+        track.credential_name(course.track_level),
+        track.credential_uri(course.track_level, ctx.accounts.learner.key),
+        utils::credential_attributes(
+            course.track_id,
+            course.track_level,
+            profile.courses_completed,
+            profile.total_xp,
+            issued_at,
+            expires_at,
+        ),
+    )?;
+
+    let credential = &mut ctx.accounts.track_credential;
+    if adopted {
+        credential.schema_version = TrackCredential::SCHEMA_VERSION;
+        credential.learner = ctx.accounts.learner.key();
+        credential.track_id = course.track_id;
+        credential.asset = existing_asset;
+        credential.courses_credited = 1;
+        credential.issued_at = issued_at;
+        credential.revoked_at = 0;
+        credential.expires_at = expires_at;
+        credential.renewed_at = 0;
+        credential._reserved = [0u8; 40];
+        credential.bump = ctx.bumps.track_credential;
+    }
+    credential.level = course.track_level;
+    credential.updated_at = now;
 
     emit!(CredentialUpgraded {
         learner: ctx.accounts.learner.key(),
         track_id: course.track_id,
-        credential_asset: ctx.accounts.credential_asset.key(),
+        credential_asset: existing_asset,
         current_level: course.track_level,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
     Ok(())
@@ -107,26 +147,65 @@ pub struct UpgradeCredential<'info> {
     )]
     pub enrollment: Account<'info, Enrollment>,
 
+    #[account(
+        seeds = [b"learner", learner.key().as_ref()],
+        bump = learner_profile.bump,
+    )]
+    pub learner_profile: Account<'info, LearnerProfile>,
+
+    /// Registry entry for the course's track
+    #[account(
+        seeds = [b"track", course.track_id.to_le_bytes().as_ref()],
+        bump = track.bump,
+    )]
+    pub track: Account<'info, Track>,
+
+    /// Learner's credential on the course's track. Created when adopting a
+    /// credential issued before track credentials existed.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TrackCredential::SIZE,
+        seeds = [b"credential", course.track_id.to_le_bytes().as_ref(), learner.key().as_ref()],
+        bump,
+    )]
+    pub track_credential: Account<'info, TrackCredential>,
+
     /// CHECK: Tied to enrollment PDA via seeds constraint.
     pub learner: AccountInfo<'info>,
 
     /// Existing credential NFT asset — not a signer, validated against enrollment record.
-    /// CHECK: Validated against enrollment.credential_asset.
+    /// CHECK: Validated against enrollment.credential_asset and track_credential.asset.
     #[account(mut)]
     pub credential_asset: AccountInfo<'info>,
 
-    /// CHECK: Metaplex Core collection for this track. Validated by Metaplex Core CPI.
-    #[account(mut)]
+    /// CHECK: Metaplex Core collection for this track. Checked against the track registry.
+    #[account(
+        mut,
+        constraint = track_collection.key() == track.collection @ AcademyError::TrackCollectionMismatch,
+    )]
     pub track_collection: AccountInfo<'info>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
 
     #[account(
-        constraint = backend_signer.key() == config.backend_signer @ AcademyError::Unauthorized,
+        constraint = utils::is_backend_signer(
+            &config,
+            backend_signer_role.as_deref(),
+            &backend_signer.key(),
+            BackendSigner::SCOPE_CREDENTIALS,
+        )? @ AcademyError::Unauthorized,
     )]
     pub backend_signer: Signer<'info>,
 
+    /// Registry entry for `backend_signer`. Omit when signing as `config.backend_signer`.
+    #[account(
+        seeds = [b"backend_signer", backend_signer.key().as_ref()],
+        bump = backend_signer_role.bump,
+    )]
+    pub backend_signer_role: Option<Account<'info, BackendSigner>>,
+
     /// CHECK: Metaplex Core program.
     #[account(address = mpl_core::ID)]
     pub mpl_core_program: AccountInfo<'info>,
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CredentialStatus, TrackCredential};
+
+/// Read-only: reports the learner's credential on a track, including whether
+/// it has expired or been revoked. Meant to be simulated by verifiers.
+pub fn handler(ctx: Context<VerifyCredential>) -> Result<CredentialStatus> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(ctx.accounts.track_credential.status(now))
+}
+
+#[derive(Accounts)]
+pub struct VerifyCredential<'info> {
+    #[account(
+        seeds = [
+            b"credential",
+            track_credential.track_id.to_le_bytes().as_ref(),
+            learner.key().as_ref(),
+        ],
+        bump = track_credential.bump,
+    )]
+    pub track_credential: Account<'info, TrackCredential>,
+
+    /// CHECK: Tied to track_credential PDA via seeds constraint.
+    pub learner: AccountInfo<'info>,
+}
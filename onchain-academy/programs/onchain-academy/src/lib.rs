@@ -25,6 +25,22 @@ pub mod onchain_academy {
         instructions::update_config::handler(ctx, params)
     }
 
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_authority_transfer::handler(ctx)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused)
+    }
+
     pub fn create_course(ctx: Context<CreateCourse>, params: CreateCourseParams) -> Result<()> {
         instructions::create_course::handler(ctx, params)
     }
@@ -33,6 +49,49 @@ pub mod onchain_academy {
         instructions::update_course::handler(ctx, params)
     }
 
+    pub fn close_course(ctx: Context<CloseCourse>, force: bool) -> Result<()> {
+        instructions::close_course::handler(ctx, force)
+    }
+
+    pub fn restructure_course(
+        ctx: Context<RestructureCourse>,
+        params: RestructureCourseParams,
+    ) -> Result<()> {
+        instructions::restructure_course::handler(ctx, params)
+    }
+
+    pub fn set_lesson_xp(ctx: Context<SetLessonXp>, lesson_xp: Vec<u32>) -> Result<()> {
+        instructions::set_lesson_xp::handler(ctx, lesson_xp)
+    }
+
+    pub fn remove_lesson_xp(ctx: Context<RemoveLessonXp>) -> Result<()> {
+        instructions::remove_lesson_xp::handler(ctx)
+    }
+
+    pub fn create_cohort(ctx: Context<CreateCohort>, params: CreateCohortParams) -> Result<()> {
+        instructions::create_cohort::handler(ctx, params)
+    }
+
+    pub fn update_cohort(ctx: Context<UpdateCohort>, params: UpdateCohortParams) -> Result<()> {
+        instructions::update_cohort::handler(ctx, params)
+    }
+
+    pub fn create_track(ctx: Context<CreateTrack>, params: CreateTrackParams) -> Result<()> {
+        instructions::create_track::handler(ctx, params)
+    }
+
+    pub fn update_track(ctx: Context<UpdateTrack>, params: UpdateTrackParams) -> Result<()> {
+        instructions::update_track::handler(ctx, params)
+    }
+
+    pub fn set_track_courses<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetTrackCourses<'info>>,
+        level: u8,
+        courses: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_track_courses::handler(ctx, level, courses)
+    }
+
     pub fn enroll<'info>(
         ctx: Context<'_, '_, 'info, 'info, Enroll<'info>>,
         course_id: String,
@@ -44,6 +103,18 @@ pub mod onchain_academy {
         instructions::complete_lesson::handler(ctx, lesson_index)
     }
 
+    pub fn complete_lessons(ctx: Context<CompleteLesson>, lesson_indices: Vec<u8>) -> Result<()> {
+        instructions::complete_lessons::handler(ctx, lesson_indices)
+    }
+
+    pub fn complete_lesson_attested(
+        ctx: Context<CompleteLessonAttested>,
+        lesson_index: u8,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::complete_lesson_attested::handler(ctx, lesson_index, expires_at)
+    }
+
     pub fn finalize_course(ctx: Context<FinalizeCourse>) -> Result<()> {
         instructions::finalize_course::handler(ctx)
     }
@@ -52,36 +123,42 @@ pub mod onchain_academy {
         instructions::close_enrollment::handler(ctx)
     }
 
-    pub fn issue_credential(
-        ctx: Context<IssueCredential>,
-        credential_name: String,
-        metadata_uri: String,
-        courses_completed: u32,
-        total_xp: u64,
+    pub fn close_orphaned_enrollment(
+        ctx: Context<CloseOrphanedEnrollment>,
+        course_id: String,
     ) -> Result<()> {
-        instructions::issue_credential::handler(
-            ctx,
-            credential_name,
-            metadata_uri,
-            courses_completed,
-            total_xp,
-        )
-    }
-
-    pub fn upgrade_credential(
-        ctx: Context<UpgradeCredential>,
-        credential_name: String,
-        metadata_uri: String,
-        courses_completed: u32,
-        total_xp: u64,
+        instructions::close_orphaned_enrollment::handler(ctx, course_id)
+    }
+
+    pub fn migrate_enrollment_version(ctx: Context<MigrateEnrollmentVersion>) -> Result<()> {
+        instructions::migrate_enrollment_version::handler(ctx)
+    }
+
+    pub fn reset_enrollment(ctx: Context<ResetEnrollment>, keep_progress: bool) -> Result<()> {
+        instructions::reset_enrollment::handler(ctx, keep_progress)
+    }
+
+    pub fn issue_credential(ctx: Context<IssueCredential>) -> Result<()> {
+        instructions::issue_credential::handler(ctx)
+    }
+
+    pub fn upgrade_credential(ctx: Context<UpgradeCredential>) -> Result<()> {
+        instructions::upgrade_credential::handler(ctx)
+    }
+
+    pub fn renew_credential(ctx: Context<RenewCredential>) -> Result<()> {
+        instructions::renew_credential::handler(ctx)
+    }
+
+    pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<state::CredentialStatus> {
+        instructions::verify_credential::handler(ctx)
+    }
+
+    pub fn revoke_credential<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevokeCredential<'info>>,
+        reason: String,
     ) -> Result<()> {
-        instructions::upgrade_credential::handler(
-            ctx,
-            credential_name,
-            metadata_uri,
-            courses_completed,
-            total_xp,
-        )
+        instructions::revoke_credential::handler(ctx, reason)
     }
 
     pub fn register_minter(
@@ -95,6 +172,28 @@ pub mod onchain_academy {
         instructions::revoke_minter::handler(ctx)
     }
 
+    pub fn add_backend_signer(
+        ctx: Context<AddBackendSigner>,
+        params: AddBackendSignerParams,
+    ) -> Result<()> {
+        instructions::add_backend_signer::handler(ctx, params)
+    }
+
+    pub fn remove_backend_signer(ctx: Context<RemoveBackendSigner>) -> Result<()> {
+        instructions::remove_backend_signer::handler(ctx)
+    }
+
+    pub fn add_course_author(
+        ctx: Context<AddCourseAuthor>,
+        params: AddCourseAuthorParams,
+    ) -> Result<()> {
+        instructions::add_course_author::handler(ctx, params)
+    }
+
+    pub fn remove_course_author(ctx: Context<RemoveCourseAuthor>) -> Result<()> {
+        instructions::remove_course_author::handler(ctx)
+    }
+
     pub fn reward_xp(ctx: Context<RewardXp>, amount: u64, memo: String) -> Result<()> {
         instructions::reward_xp::handler(ctx, amount, memo)
     }
@@ -110,7 +209,34 @@ pub mod onchain_academy {
         instructions::award_achievement::handler(ctx)
     }
 
+    pub fn revoke_achievement(ctx: Context<RevokeAchievement>, reason: String) -> Result<()> {
+        instructions::revoke_achievement::handler(ctx, reason)
+    }
+
     pub fn deactivate_achievement_type(ctx: Context<DeactivateAchievementType>) -> Result<()> {
         instructions::deactivate_achievement_type::handler(ctx)
     }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config::handler(ctx)
+    }
+
+    pub fn migrate_course(ctx: Context<MigrateCourse>) -> Result<()> {
+        instructions::migrate_course::handler(ctx)
+    }
+
+    pub fn migrate_enrollment(ctx: Context<MigrateEnrollment>) -> Result<()> {
+        instructions::migrate_enrollment::handler(ctx)
+    }
+
+    pub fn migrate_minter_role(
+        ctx: Context<MigrateMinterRole>,
+        params: MigrateMinterRoleParams,
+    ) -> Result<()> {
+        instructions::migrate_minter_role::handler(ctx, params)
+    }
+
+    pub fn migrate_achievement_type(ctx: Context<MigrateAchievementType>) -> Result<()> {
+        instructions::migrate_achievement_type::handler(ctx)
+    }
 }
@@ -6,6 +6,8 @@ pub const MAX_ACHIEVEMENT_URI_LEN: usize = 128;
 
 #[account]
 pub struct AchievementType {
+    /// Account layout version (see `AchievementType::SCHEMA_VERSION`)
+    pub schema_version: u8,
     pub achievement_id: String,
     pub name: String,
     /// Default metadata URI for minted NFTs
@@ -20,12 +22,18 @@ pub struct AchievementType {
     pub xp_reward: u32,
     pub is_active: bool,
     pub created_at: i64,
-    pub _reserved: [u8; 8],
+    /// Awards revoked so far. Frees supply under `max_supply` while
+    /// `current_supply` keeps numbering new awards.
+    pub revoked_count: u32,
+    pub _reserved: [u8; 28],
     pub bump: u8,
 }
 
 impl AchievementType {
+    pub const SCHEMA_VERSION: u8 = 1;
+
     // 8 (discriminator)
+    // + 1 (schema_version)
     // + (4 + 32) (achievement_id)
     // + (4 + 64) (name)
     // + (4 + 128) (metadata_uri)
@@ -36,9 +44,11 @@ impl AchievementType {
     // + 4 (xp_reward)
     // + 1 (is_active)
     // + 8 (created_at)
-    // + 8 (_reserved)
+    // + 4 (revoked_count)
+    // + 28 (_reserved)
     // + 1 (bump)
     pub const SIZE: usize = 8
+        + 1
         + (4 + MAX_ACHIEVEMENT_ID_LEN)
         + (4 + MAX_ACHIEVEMENT_NAME_LEN)
         + (4 + MAX_ACHIEVEMENT_URI_LEN)
@@ -49,8 +59,37 @@ impl AchievementType {
         + 4
         + 1
         + 8
-        + 8
-        + 1; // 338
+        + 4
+        + 28
+        + 1; // 363
+
+    /// Size of unversioned (schema 0) accounts; see `migrate_achievement_type`.
+    pub const LEGACY_SIZE: usize = 338;
+
+    /// True if another award fits under `max_supply` (0 = unlimited).
+    /// Revoked awards no longer count against the cap.
+    pub fn has_supply(&self) -> bool {
+        self.max_supply == 0
+            || self.current_supply.saturating_sub(self.revoked_count) < self.max_supply
+    }
+}
+
+/// Unversioned AchievementType layout (schema 0). Only used by
+/// `migrate_achievement_type`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AchievementTypeV0 {
+    pub achievement_id: String,
+    pub name: String,
+    pub metadata_uri: String,
+    pub collection: Pubkey,
+    pub creator: Pubkey,
+    pub max_supply: u32,
+    pub current_supply: u32,
+    pub xp_reward: u32,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub _reserved: [u8; 8],
+    pub bump: u8,
 }
 
 /// Thin PDA for on-chain double-award prevention.
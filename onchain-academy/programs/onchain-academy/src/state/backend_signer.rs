@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use super::minter_role::MAX_LABEL_LEN;
+
+/// Additional backend key allowed to sign learner-progress instructions.
+/// Seeds: ["backend_signer", signer.key()]
+/// `config.backend_signer` keeps all scopes without a registry entry.
+#[account]
+pub struct BackendSigner {
+    /// Account layout version (see `BackendSigner::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    /// Key that signs as backend
+    pub signer: Pubkey,
+    /// Human-readable label ("eu-west", "credential-worker", etc.)
+    pub label: String,
+    /// Bitmask of `BackendSigner::SCOPE_*` flags
+    pub scopes: u8,
+    /// Unix timestamp from which the signer is rejected. 0 = never expires.
+    pub expires_at: i64,
+    pub created_at: i64,
+    pub _reserved: [u8; 32],
+    pub bump: u8,
+}
+
+impl BackendSigner {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    /// complete_lesson
+    pub const SCOPE_LESSONS: u8 = 1 << 0;
+    /// finalize_course
+    pub const SCOPE_FINALIZE: u8 = 1 << 1;
+    /// issue_credential, upgrade_credential
+    pub const SCOPE_CREDENTIALS: u8 = 1 << 2;
+    pub const ALL_SCOPES: u8 = Self::SCOPE_LESSONS | Self::SCOPE_FINALIZE | Self::SCOPE_CREDENTIALS;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (signer)
+    // + (4 + 32) (label)
+    // + 1 (scopes)
+    // + 8 (expires_at)
+    // + 8 (created_at)
+    // + 32 (_reserved)
+    // + 1 (bump)
+    pub const SIZE: usize = 8 + 1 + 32 + (4 + MAX_LABEL_LEN) + 1 + 8 + 8 + 32 + 1; // 127
+
+    /// True if this entry grants `scope` and has not expired at `now`.
+    pub fn allows(&self, scope: u8, now: i64) -> bool {
+        self.scopes & scope == scope && (self.expires_at == 0 || now < self.expires_at)
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use super::course::{before_deadline, has_capacity, window_contains};
+
+/// One scheduled run of a course with its own window, seats and counters.
+/// Seeds: ["cohort", course.key(), cohort_id.to_le_bytes()]
+#[account]
+pub struct Cohort {
+    /// Account layout version (see `Cohort::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    /// The Course PDA this cohort runs
+    pub course: Pubkey,
+    pub cohort_id: u16,
+    /// Enrollment opens at this unix timestamp. 0 = no lower bound.
+    pub enroll_opens_at: i64,
+    /// Enrollment closes at this unix timestamp. 0 = no upper bound.
+    pub enroll_closes_at: i64,
+    /// `finalize_course` is rejected after this unix timestamp. 0 = no deadline.
+    pub completion_deadline: i64,
+    /// Seats in this cohort. 0 = unlimited (the course cap still applies).
+    pub max_enrollments: u32,
+    pub total_enrollments: u32,
+    pub total_completions: u32,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub _reserved: [u8; 32],
+    pub bump: u8,
+}
+
+impl Cohort {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (course)
+    // + 2 (cohort_id)
+    // + 8 (enroll_opens_at)
+    // + 8 (enroll_closes_at)
+    // + 8 (completion_deadline)
+    // + 4 (max_enrollments)
+    // + 4 (total_enrollments)
+    // + 4 (total_completions)
+    // + 1 (is_active)
+    // + 8 (created_at)
+    // + 32 (_reserved)
+    // + 1 (bump)
+    pub const SIZE: usize = 8 + 1 + 32 + 2 + 8 + 8 + 8 + 4 + 4 + 4 + 1 + 8 + 32 + 1; // 121
+
+    /// Whether learners may join this cohort at `now`.
+    pub fn enrollment_open(&self, now: i64) -> bool {
+        self.is_active && window_contains(self.enroll_opens_at, self.enroll_closes_at, now)
+    }
+
+    pub fn has_capacity(&self) -> bool {
+        has_capacity(self.total_enrollments, self.max_enrollments)
+    }
+
+    pub fn before_deadline(&self, now: i64) -> bool {
+        before_deadline(self.completion_deadline, now)
+    }
+}
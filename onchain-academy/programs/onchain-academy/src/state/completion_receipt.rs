@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Compact proof of a completed course, left behind when the learner closes
+/// a completed Enrollment. Seeds: ["completion", course.key(), learner.key()]
+#[account]
+pub struct CompletionReceipt {
+    /// Account layout version (see `CompletionReceipt::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    /// The Course PDA that was completed
+    pub course: Pubkey,
+    /// `Enrollment.completed_at` of the closed enrollment
+    pub completed_at: i64,
+    /// Credential NFT issued for the enrollment, if any
+    pub credential_asset: Option<Pubkey>,
+    /// `Course.version` when the enrollment was closed
+    pub course_version: u16,
+    pub _reserved: [u8; 8],
+    pub bump: u8,
+}
+
+impl CompletionReceipt {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (course)
+    // + 8 (completed_at)
+    // + 1 + 32 (credential_asset: Option<Pubkey>)
+    // + 2 (course_version)
+    // + 8 (_reserved)
+    // + 1 (bump)
+    pub const SIZE: usize = 8 + 1 + 32 + 8 + (1 + 32) + 2 + 8 + 1; // 93
+}
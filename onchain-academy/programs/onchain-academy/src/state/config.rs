@@ -1,19 +1,77 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::AcademyError;
+
 #[account]
 pub struct Config {
+    /// Account layout version (see `Config::SCHEMA_VERSION`)
+    pub schema_version: u8,
     /// Platform multisig (Squads)
     pub authority: Pubkey,
     /// Rotatable backend signer for completions
     pub backend_signer: Pubkey,
     /// Token-2022 mint for XP
     pub xp_mint: Pubkey,
+    /// Per-learner XP ceiling per UTC day. 0 = no cap.
+    pub daily_xp_cap: u64,
+    /// Authority proposed via `propose_authority`. Default = no transfer pending.
+    pub pending_authority: Pubkey,
+    /// Bitmask of `Config::PAUSE_*` flags currently in effect
+    pub paused: u8,
+    /// Optional key that may pause (but not unpause). Default = none.
+    pub guardian: Pubkey,
     /// Reserved for future use
-    pub _reserved: [u8; 8],
+    pub _reserved: [u8; 63],
     /// PDA bump
     pub bump: u8,
 }
 
 impl Config {
-    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1; // 113
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    /// Every learner, backend and minter instruction
+    pub const PAUSE_ALL: u8 = 1 << 0;
+    /// complete_lesson, finalize_course, reward_xp, award_achievement with XP
+    pub const PAUSE_XP: u8 = 1 << 1;
+    /// issue_credential, upgrade_credential
+    pub const PAUSE_CREDENTIALS: u8 = 1 << 2;
+    /// award_achievement
+    pub const PAUSE_ACHIEVEMENTS: u8 = 1 << 3;
+    pub const PAUSE_FLAGS: u8 =
+        Self::PAUSE_ALL | Self::PAUSE_XP | Self::PAUSE_CREDENTIALS | Self::PAUSE_ACHIEVEMENTS;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (authority)
+    // + 32 (backend_signer)
+    // + 32 (xp_mint)
+    // + 8 (daily_xp_cap)
+    // + 32 (pending_authority)
+    // + 1 (paused)
+    // + 32 (guardian)
+    // + 63 (_reserved)
+    // + 1 (bump)
+    pub const SIZE: usize = 8 + 1 + 32 + 32 + 32 + 8 + 32 + 1 + 32 + 63 + 1; // 242
+
+    /// Size of unversioned (schema 0) accounts; see `migrate_config`.
+    pub const LEGACY_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1; // 113
+
+    /// Fails with `ProgramPaused` if the global pause or `flag` is set.
+    pub fn require_not_paused(&self, flag: u8) -> Result<()> {
+        require!(
+            self.paused & (Self::PAUSE_ALL | flag) == 0,
+            AcademyError::ProgramPaused
+        );
+        Ok(())
+    }
+}
+
+/// Unversioned Config layout (schema 0). Only used by `migrate_config`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigV0 {
+    pub authority: Pubkey,
+    pub backend_signer: Pubkey,
+    pub xp_mint: Pubkey,
+    pub daily_xp_cap: u64,
+    pub bump: u8,
 }
@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
 
+use super::course_lesson_xp::CourseLessonXp;
+use crate::errors::AcademyError;
+
 pub const MAX_COURSE_ID_LEN: usize = 32;
+/// `prerequisite` plus `additional_prerequisites`
+pub const MAX_PREREQUISITES: usize = 4;
+/// 100%, the ceiling for `completion_bonus_bps`
+pub const MAX_BASIS_POINTS: u16 = 10_000;
 
 #[account]
 pub struct Course {
+    /// Account layout version (see `Course::SCHEMA_VERSION`)
+    pub schema_version: u8,
     pub course_id: String,
     /// XP recipient for creator rewards (not an authority — all admin goes through Config)
     pub creator: Pubkey,
@@ -22,12 +31,47 @@ pub struct Course {
     pub is_active: bool,
     pub created_at: i64,
     pub updated_at: i64,
-    pub _reserved: [u8; 8],
+    /// XP minted to the learner by `finalize_course`. 0 = no bonus.
+    pub completion_bonus_xp: u32,
+    /// A `CourseLessonXp` table overrides `xp_per_lesson` (see `set_lesson_xp`)
+    pub has_lesson_xp: bool,
+    /// Prerequisites after `prerequisite`; unused slots are `Pubkey::default()`
+    pub additional_prerequisites: [Pubkey; 3],
+    /// How many prerequisites must be completed. 0 = all of them.
+    pub prerequisite_min_count: u8,
+    /// Enrollment opens at this unix timestamp. 0 = no lower bound.
+    pub enroll_opens_at: i64,
+    /// Enrollment closes at this unix timestamp. 0 = no upper bound.
+    pub enroll_closes_at: i64,
+    /// `finalize_course` is rejected after this unix timestamp. 0 = no deadline.
+    pub completion_deadline: i64,
+    /// Cap on `total_enrollments`. 0 = unlimited.
+    pub max_enrollments: u32,
+    /// Enrollment must go through a `Cohort`; the course-level window is ignored
+    pub cohort_only: bool,
+    /// Seconds an enrollment attempt stays valid before its progress is
+    /// stale and must be reset. 0 = never.
+    pub progress_expires_after: i64,
+    /// `version` of the last `restructure_course`. 0 = never restructured.
+    pub lesson_layout_version: u16,
+    /// Enrollment PDAs not yet closed. `migrate_course` can only estimate it
+    /// (as `total_enrollments`), so `close_enrollment` saturates at 0.
+    pub open_enrollments: u32,
+    /// `CourseAuthor` allowed to edit content and XP alongside the authority.
+    /// Default = authority only.
+    pub author: Pubkey,
+    /// Extra completion bonus in basis points of the course's total lesson
+    /// XP, added to `completion_bonus_xp`. At most `MAX_BASIS_POINTS`.
+    pub completion_bonus_bps: u16,
+    pub _reserved: [u8; 77],
     pub bump: u8,
 }
 
 impl Course {
+    pub const SCHEMA_VERSION: u8 = 1;
+
     // 8 (discriminator)
+    // + 1 (schema_version)
     // + (4 + 32) (course_id)
     // + 32 (creator)
     // + 32 (content_tx_id)
@@ -45,9 +89,24 @@ impl Course {
     // + 1 (is_active)
     // + 8 (created_at)
     // + 8 (updated_at)
-    // + 8 (_reserved)
+    // + 4 (completion_bonus_xp)
+    // + 1 (has_lesson_xp)
+    // + 32 * 3 (additional_prerequisites)
+    // + 1 (prerequisite_min_count)
+    // + 8 (enroll_opens_at)
+    // + 8 (enroll_closes_at)
+    // + 8 (completion_deadline)
+    // + 4 (max_enrollments)
+    // + 1 (cohort_only)
+    // + 8 (progress_expires_after)
+    // + 2 (lesson_layout_version)
+    // + 4 (open_enrollments)
+    // + 32 (author)
+    // + 2 (completion_bonus_bps)
+    // + 77 (_reserved)
     // + 1 (bump)
     pub const SIZE: usize = 8
+        + 1
         + (4 + MAX_COURSE_ID_LEN)
         + 32
         + 32
@@ -65,6 +124,219 @@ impl Course {
         + 1
         + 8
         + 8
+        + 4
+        + 1
+        + 32 * 3
+        + 1
+        + 8
         + 8
-        + 1; // 192
+        + 8
+        + 4
+        + 1
+        + 8
+        + 2
+        + 4
+        + 32
+        + 2
+        + 77
+        + 1; // 441
+
+    /// Size of unversioned (schema 0) accounts; see `migrate_course`.
+    pub const LEGACY_SIZE: usize = 192;
+
+    /// Bonus schema 0 courses paid implicitly, 50% of total lesson XP.
+    /// Backfilled into `completion_bonus_bps` by `migrate_course`.
+    pub const LEGACY_COMPLETION_BONUS_BPS: u16 = 5_000;
+
+    /// XP for completing `lesson_index`: the table entry when the course has a
+    /// `CourseLessonXp` table, otherwise `xp_per_lesson`. Fails with
+    /// `LessonXpTableRequired` if the course has a table but none was passed.
+    pub fn lesson_xp(&self, lesson_index: u8, table: Option<&CourseLessonXp>) -> Result<u32> {
+        if !self.has_lesson_xp {
+            return Ok(self.xp_per_lesson);
+        }
+        let table = table.ok_or(AcademyError::LessonXpTableRequired)?;
+        table
+            .lesson_xp
+            .get(lesson_index as usize)
+            .copied()
+            .ok_or_else(|| error!(AcademyError::LessonOutOfBounds))
+    }
+
+    /// XP for completing every lesson, honouring the per-lesson table.
+    pub fn total_lesson_xp(&self, table: Option<&CourseLessonXp>) -> Result<u64> {
+        if !self.has_lesson_xp {
+            return (self.xp_per_lesson as u64)
+                .checked_mul(self.lesson_count as u64)
+                .ok_or_else(|| error!(AcademyError::Overflow));
+        }
+        let table = table.ok_or(AcademyError::LessonXpTableRequired)?;
+        Ok(table.lesson_xp.iter().map(|&xp| xp as u64).sum())
+    }
+
+    /// Bonus minted by `finalize_course`: `completion_bonus_xp` plus
+    /// `completion_bonus_bps` of `total_lesson_xp`.
+    pub fn completion_bonus(&self, total_lesson_xp: u64) -> Result<u64> {
+        let scaled = total_lesson_xp
+            .checked_mul(self.completion_bonus_bps as u64)
+            .ok_or(AcademyError::Overflow)?
+            / MAX_BASIS_POINTS as u64;
+        scaled
+            .checked_add(self.completion_bonus_xp as u64)
+            .ok_or_else(|| error!(AcademyError::Overflow))
+    }
+
+    /// Every prerequisite course, in the order they were configured.
+    pub fn prerequisites(&self) -> Vec<Pubkey> {
+        self.prerequisite
+            .into_iter()
+            .chain(
+                self.additional_prerequisites
+                    .iter()
+                    .copied()
+                    .filter(|key| *key != Pubkey::default()),
+            )
+            .collect()
+    }
+
+    /// Number of prerequisites a learner must have completed to enroll.
+    pub fn required_prerequisites(&self) -> usize {
+        let total = self.prerequisites().len();
+        match self.prerequisite_min_count {
+            0 => total,
+            min => (min as usize).min(total),
+        }
+    }
+
+    /// Whether `completed` (prerequisite courses the learner has finished)
+    /// satisfies the course's prerequisite set. Duplicates count once.
+    pub fn prerequisites_met(&self, completed: &[Pubkey]) -> bool {
+        let satisfied = self
+            .prerequisites()
+            .iter()
+            .filter(|key| completed.contains(key))
+            .count();
+        satisfied >= self.required_prerequisites()
+    }
+
+    /// Whether the course-level enrollment window is open at `now`.
+    pub fn enrollment_open(&self, now: i64) -> bool {
+        window_contains(self.enroll_opens_at, self.enroll_closes_at, now)
+    }
+
+    /// Whether another learner may enroll under `max_enrollments`.
+    pub fn has_capacity(&self) -> bool {
+        has_capacity(self.total_enrollments, self.max_enrollments)
+    }
+
+    /// Whether a credential at `level` on `track_id` stands in for completing
+    /// this course when it is used as a prerequisite.
+    pub fn credential_satisfies(&self, track_id: u16, level: u8) -> bool {
+        track_id == self.track_id && level >= self.track_level
+    }
+
+    /// Checks a `lesson_count` change made outside `restructure_course`.
+    /// Appending lessons keeps every index, so it is always allowed; dropping
+    /// lessons could strand completed bits and is only allowed before anyone
+    /// has enrolled. A per-lesson XP table pins the count either way.
+    pub fn validate_lesson_count_update(&self, new_lesson_count: u8) -> Result<()> {
+        require!(new_lesson_count > 0, AcademyError::InvalidLessonCount);
+        if new_lesson_count == self.lesson_count {
+            return Ok(());
+        }
+        require!(!self.has_lesson_xp, AcademyError::LessonXpTablePresent);
+        require!(
+            new_lesson_count > self.lesson_count || self.total_enrollments == 0,
+            AcademyError::LessonCountShrinkRequiresRestructure
+        );
+        Ok(())
+    }
+
+    /// Replace the prerequisite set. `min_count` of 0 requires all of them
+    /// (AND); 1 requires any one (OR); anything in between is "k of n".
+    pub fn set_prerequisites(&mut self, prerequisites: &[Pubkey], min_count: u8) -> Result<()> {
+        require!(
+            prerequisites.len() <= MAX_PREREQUISITES,
+            AcademyError::TooManyPrerequisites
+        );
+        require!(
+            (min_count as usize) <= prerequisites.len(),
+            AcademyError::InvalidPrerequisites
+        );
+        for (i, key) in prerequisites.iter().enumerate() {
+            require!(
+                *key != Pubkey::default() && !prerequisites[..i].contains(key),
+                AcademyError::InvalidPrerequisites
+            );
+        }
+
+        self.prerequisite = prerequisites.first().copied();
+        self.additional_prerequisites = [Pubkey::default(); 3];
+        for (slot, key) in self
+            .additional_prerequisites
+            .iter_mut()
+            .zip(prerequisites.iter().skip(1))
+        {
+            *slot = *key;
+        }
+        self.prerequisite_min_count = min_count;
+        Ok(())
+    }
+}
+
+/// True if `now` falls in `[opens_at, closes_at)`; 0 leaves either end open.
+pub fn window_contains(opens_at: i64, closes_at: i64, now: i64) -> bool {
+    (opens_at == 0 || now >= opens_at) && (closes_at == 0 || now < closes_at)
+}
+
+/// True if `count` is below `max`; a `max` of 0 is unlimited.
+pub fn has_capacity(count: u32, max: u32) -> bool {
+    max == 0 || count < max
+}
+
+/// True if `now` is not past `deadline`; a `deadline` of 0 never passes.
+pub fn before_deadline(deadline: i64, now: i64) -> bool {
+    deadline == 0 || now <= deadline
+}
+
+/// Validates an enrollment window and completion deadline (0 = unset): the
+/// window must not be empty and the deadline cannot precede its close.
+pub fn validate_schedule(opens_at: i64, closes_at: i64, completion_deadline: i64) -> Result<()> {
+    require!(
+        opens_at >= 0 && closes_at >= 0 && completion_deadline >= 0,
+        AcademyError::InvalidSchedule
+    );
+    require!(
+        opens_at == 0 || closes_at == 0 || closes_at > opens_at,
+        AcademyError::InvalidSchedule
+    );
+    require!(
+        completion_deadline == 0 || completion_deadline >= closes_at.max(opens_at),
+        AcademyError::InvalidSchedule
+    );
+    Ok(())
+}
+
+/// Unversioned Course layout (schema 0). Only used by `migrate_course`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CourseV0 {
+    pub course_id: String,
+    pub creator: Pubkey,
+    pub content_tx_id: [u8; 32],
+    pub version: u16,
+    pub lesson_count: u8,
+    pub difficulty: u8,
+    pub xp_per_lesson: u32,
+    pub track_id: u16,
+    pub track_level: u8,
+    pub prerequisite: Option<Pubkey>,
+    pub creator_reward_xp: u32,
+    pub min_completions_for_reward: u16,
+    pub total_completions: u32,
+    pub total_enrollments: u32,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub _reserved: [u8; 8],
+    pub bump: u8,
 }
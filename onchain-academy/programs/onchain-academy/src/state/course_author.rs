@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use super::minter_role::MAX_LABEL_LEN;
+
+/// Tracks a single author may be assigned
+pub const MAX_AUTHOR_TRACKS: usize = 8;
+
+/// Delegated course author. May create courses on its assigned tracks and
+/// edit content and XP of courses whose `Course.author` it is, without a
+/// multisig vote. Seeds: ["course_author", author.key()]
+#[account]
+pub struct CourseAuthor {
+    /// Account layout version (see `CourseAuthor::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    /// Key that signs as author
+    pub author: Pubkey,
+    /// Human-readable label ("solana-core-team", etc.)
+    pub label: String,
+    /// `track_id`s the author may publish on, up to `MAX_AUTHOR_TRACKS`
+    pub track_ids: Vec<u16>,
+    pub created_at: i64,
+    pub _reserved: [u8; 32],
+    pub bump: u8,
+}
+
+impl CourseAuthor {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (author)
+    // + (4 + 32) (label)
+    // + (4 + 2 * 8) (track_ids)
+    // + 8 (created_at)
+    // + 32 (_reserved)
+    // + 1 (bump)
+    pub const SIZE: usize =
+        8 + 1 + 32 + (4 + MAX_LABEL_LEN) + (4 + 2 * MAX_AUTHOR_TRACKS) + 8 + 32 + 1; // 138
+
+    /// Whether the author is assigned to `track_id`.
+    pub fn allows_track(&self, track_id: u16) -> bool {
+        self.track_ids.contains(&track_id)
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Optional per-lesson XP table for a course. Seeds: ["lesson_xp", course.key()]
+/// When present (`course.has_lesson_xp`), it replaces `course.xp_per_lesson`.
+#[account]
+pub struct CourseLessonXp {
+    /// Account layout version (see `CourseLessonXp::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    /// The Course PDA this table belongs to
+    pub course: Pubkey,
+    /// XP awarded for each lesson; length equals `course.lesson_count`
+    pub lesson_xp: Vec<u32>,
+    pub _reserved: [u8; 16],
+    pub bump: u8,
+}
+
+impl CourseLessonXp {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (course)
+    // + (4 + 4 * lesson_count) (lesson_xp)
+    // + 16 (_reserved)
+    // + 1 (bump)
+    pub fn space(lesson_count: u8) -> usize {
+        8 + 1 + 32 + (4 + 4 * lesson_count as usize) + 16 + 1
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Left behind by `close_course` so the course ID is never reused.
+/// Seeds: ["course_tombstone", course_id.as_bytes()]
+#[account]
+pub struct CourseTombstone {
+    /// Account layout version (see `CourseTombstone::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    /// The closed Course PDA
+    pub course: Pubkey,
+    pub closed_at: i64,
+    pub _reserved: [u8; 8],
+    pub bump: u8,
+}
+
+impl CourseTombstone {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (course)
+    // + 8 (closed_at)
+    // + 8 (_reserved)
+    // + 1 (bump)
+    pub const SIZE: usize = 8 + 1 + 32 + 8 + 8 + 1; // 58
+}
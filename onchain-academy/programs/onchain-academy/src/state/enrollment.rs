@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 
 #[account]
 pub struct Enrollment {
+    /// Account layout version (see `Enrollment::SCHEMA_VERSION`)
+    pub schema_version: u8,
     /// The Course PDA this enrollment belongs to
     pub course: Pubkey,
     /// When learner enrolled
@@ -13,20 +15,70 @@ pub struct Enrollment {
     pub lesson_flags: [u64; 4],
     /// Credential NFT address for this track (set by issue_credential)
     pub credential_asset: Option<Pubkey>,
-    /// Reserved for future use (4 bytes — differs from 8 on other accounts; cannot resize without migration)
-    pub _reserved: [u8; 4],
+    /// Cohort PDA the learner joined. `Pubkey::default()` = no cohort.
+    pub cohort: Pubkey,
+    /// Number of `reset_enrollment` calls; 0 on the first attempt
+    pub attempt: u16,
+    /// Start of the current attempt, for `Course.progress_expires_after`.
+    /// 0 = `enrolled_at` (enrollments that predate the field).
+    pub attempt_started_at: i64,
+    /// `Course.version` whose lesson layout `lesson_flags` follows. 0 = the
+    /// layout before the course's first `restructure_course`.
+    pub course_version: u16,
+    /// Reserved for future use
+    pub _reserved: [u8; 20],
     /// PDA bump
     pub bump: u8,
 }
 
 impl Enrollment {
+    pub const SCHEMA_VERSION: u8 = 1;
+
     // 8 (discriminator)
+    // + 1 (schema_version)
     // + 32 (course)
     // + 8 (enrolled_at)
     // + 1 + 8 (completed_at: Option<i64>)
     // + 32 (lesson_flags: [u64; 4])
     // + 1 + 32 (credential_asset: Option<Pubkey>)
-    // + 4 (_reserved)
+    // + 32 (cohort)
+    // + 2 (attempt)
+    // + 8 (attempt_started_at)
+    // + 2 (course_version)
+    // + 20 (_reserved)
     // + 1 (bump)
-    pub const SIZE: usize = 8 + 32 + 8 + (1 + 8) + 32 + (1 + 32) + 4 + 1; // 127
+    pub const SIZE: usize = 8 + 1 + 32 + 8 + (1 + 8) + 32 + (1 + 32) + 32 + 2 + 8 + 2 + 20 + 1; // 188
+
+    /// Size of unversioned (schema 0) accounts; see `migrate_enrollment`.
+    pub const LEGACY_SIZE: usize = 127;
+
+    /// Whether `lesson_flags` predate the course's current lesson layout and
+    /// must go through `migrate_enrollment_version` first.
+    pub fn needs_lesson_migration(&self, lesson_layout_version: u16) -> bool {
+        self.course_version < lesson_layout_version
+    }
+
+    /// Whether the current attempt has outlived `progress_expires_after`
+    /// seconds (0 = never), after which it must be reset before continuing.
+    pub fn progress_expired(&self, progress_expires_after: i64, now: i64) -> bool {
+        let started_at = if self.attempt_started_at == 0 {
+            self.enrolled_at
+        } else {
+            self.attempt_started_at
+        };
+        progress_expires_after > 0 && now.saturating_sub(started_at) > progress_expires_after
+    }
+}
+
+/// Unversioned Enrollment layout (schema 0, 4 reserved bytes). Only used by
+/// `migrate_enrollment`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EnrollmentV0 {
+    pub course: Pubkey,
+    pub enrolled_at: i64,
+    pub completed_at: Option<i64>,
+    pub lesson_flags: [u64; 4],
+    pub credential_asset: Option<Pubkey>,
+    pub _reserved: [u8; 4],
+    pub bump: u8,
 }
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AcademyError;
+
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Lifetime learner stats. Seeds: ["learner", learner.key()]
+/// Created lazily on first enroll, or by `migrate_enrollment` for learners
+/// who enrolled before profiles existed; credentials read their stats from here.
+#[account]
+pub struct LearnerProfile {
+    /// Account layout version (see `LearnerProfile::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    /// Wallet this profile belongs to
+    pub learner: Pubkey,
+    /// Lifetime XP minted to this learner through the program
+    pub total_xp: u64,
+    /// Number of finalized courses
+    pub courses_completed: u32,
+    /// Consecutive days with lesson or course activity
+    pub current_streak: u16,
+    pub longest_streak: u16,
+    /// Unix timestamp of the last streak-counting activity (0 = none yet)
+    pub last_activity_at: i64,
+    /// Day index (unix_timestamp / 86400) that `daily_xp_earned` refers to
+    pub daily_xp_day: i64,
+    /// XP minted to this learner during `daily_xp_day`
+    pub daily_xp_earned: u64,
+    pub created_at: i64,
+    pub _reserved: [u8; 64],
+    pub bump: u8,
+}
+
+impl LearnerProfile {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (learner)
+    // + 8 (total_xp)
+    // + 4 (courses_completed)
+    // + 2 (current_streak)
+    // + 2 (longest_streak)
+    // + 8 (last_activity_at)
+    // + 8 (daily_xp_day)
+    // + 8 (daily_xp_earned)
+    // + 8 (created_at)
+    // + 64 (_reserved)
+    // + 1 (bump)
+    pub const SIZE: usize = 8 + 1 + 32 + 8 + 4 + 2 + 2 + 8 + 8 + 8 + 8 + 64 + 1; // 154
+
+    /// Populates a freshly allocated profile. No-op once `learner` is set,
+    /// so callers using `init_if_needed` can call this unconditionally.
+    pub fn init_if_new(&mut self, learner: Pubkey, now: i64, bump: u8) {
+        if self.learner != Pubkey::default() {
+            return;
+        }
+        self.schema_version = Self::SCHEMA_VERSION;
+        self.learner = learner;
+        self.total_xp = 0;
+        self.courses_completed = 0;
+        self.current_streak = 0;
+        self.longest_streak = 0;
+        self.last_activity_at = 0;
+        self.daily_xp_day = 0;
+        self.daily_xp_earned = 0;
+        self.created_at = now;
+        self._reserved = [0u8; 64];
+        self.bump = bump;
+    }
+
+    /// Folds in history from before profiles existed, once per migrated
+    /// schema 0 enrollment: a completed enrollment counts as a finished
+    /// course, and `total_xp` is raised to the learner's XP balance.
+    pub fn backfill_legacy(&mut self, completed: bool, xp_balance: u64) -> Result<()> {
+        if completed {
+            self.courses_completed = self
+                .courses_completed
+                .checked_add(1)
+                .ok_or(AcademyError::Overflow)?;
+        }
+        self.total_xp = self.total_xp.max(xp_balance);
+        Ok(())
+    }
+
+    /// Adds `amount` to the lifetime and per-day counters. Call before minting:
+    /// fails with `DailyXpCapExceeded` if today's total would exceed
+    /// `daily_cap` (0 = no cap). The day bucket rolls over at UTC midnight.
+    pub fn record_xp(&mut self, amount: u64, now: i64, daily_cap: u64) -> Result<()> {
+        let today = now.div_euclid(SECONDS_PER_DAY);
+        if self.daily_xp_day != today {
+            self.daily_xp_day = today;
+            self.daily_xp_earned = 0;
+        }
+
+        let earned_today = self
+            .daily_xp_earned
+            .checked_add(amount)
+            .ok_or(AcademyError::Overflow)?;
+        if daily_cap > 0 {
+            require!(earned_today <= daily_cap, AcademyError::DailyXpCapExceeded);
+        }
+
+        self.daily_xp_earned = earned_today;
+        self.total_xp = self
+            .total_xp
+            .checked_add(amount)
+            .ok_or(AcademyError::Overflow)?;
+        Ok(())
+    }
+
+    /// Advances the daily streak. Same-day activity is a no-op, activity on
+    /// the following day extends the streak, and any longer gap resets it to 1.
+    pub fn record_activity(&mut self, now: i64) {
+        let today = now.div_euclid(SECONDS_PER_DAY);
+        let last_day = self.last_activity_at.div_euclid(SECONDS_PER_DAY);
+
+        if self.current_streak > 0 && today == last_day {
+            return;
+        }
+
+        self.current_streak = if self.current_streak > 0 && today == last_day + 1 {
+            self.current_streak.saturating_add(1)
+        } else {
+            1
+        };
+        self.longest_streak = self.longest_streak.max(self.current_streak);
+        self.last_activity_at = now;
+    }
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+/// Published by `restructure_course`: where each lesson of the previous
+/// layout moved to. Seeds: ["lesson_map", course.key(), to_version.to_le_bytes()]
+#[account]
+pub struct LessonMap {
+    /// Account layout version (see `LessonMap::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    /// The Course PDA this map belongs to
+    pub course: Pubkey,
+    /// `course.lesson_layout_version` before the restructure. Enrollments
+    /// recorded at any version in `from_version..to_version` use this map.
+    pub from_version: u16,
+    /// `course.version` the restructure produced
+    pub to_version: u16,
+    pub new_lesson_count: u8,
+    /// New index for each old lesson; `LessonMap::REMOVED` drops it
+    pub mapping: Vec<u8>,
+    pub _reserved: [u8; 16],
+    pub bump: u8,
+}
+
+impl LessonMap {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    /// Mapping entry for a lesson that no longer exists
+    pub const REMOVED: u8 = u8::MAX;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (course)
+    // + 2 (from_version)
+    // + 2 (to_version)
+    // + 1 (new_lesson_count)
+    // + (4 + old_lesson_count) (mapping)
+    // + 16 (_reserved)
+    // + 1 (bump)
+    pub fn space(old_lesson_count: u8) -> usize {
+        8 + 1 + 32 + 2 + 2 + 1 + (4 + old_lesson_count as usize) + 16 + 1
+    }
+
+    /// True if every entry is `REMOVED` or a distinct index below `new_lesson_count`.
+    pub fn is_valid_mapping(mapping: &[u8], new_lesson_count: u8) -> bool {
+        let mut seen = [0u64; 4];
+        mapping.iter().all(|&new_index| {
+            if new_index == Self::REMOVED {
+                return true;
+            }
+            let (word, mask) = ((new_index / 64) as usize, 1u64 << (new_index % 64));
+            let fresh = new_index < new_lesson_count && seen[word] & mask == 0;
+            seen[word] |= mask;
+            fresh
+        })
+    }
+
+    /// Whether an enrollment recorded at `course_version` is migrated by this map.
+    pub fn applies_to(&self, course_version: u16) -> bool {
+        (self.from_version..self.to_version).contains(&course_version)
+    }
+
+    /// Moves each completed lesson bit to its new index, dropping removed lessons.
+    pub fn remap(&self, lesson_flags: &[u64; 4]) -> [u64; 4] {
+        let mut remapped = [0u64; 4];
+        for (old_index, &new_index) in self.mapping.iter().enumerate() {
+            let completed = lesson_flags[old_index / 64] & (1u64 << (old_index % 64)) != 0;
+            if completed && new_index != Self::REMOVED {
+                remapped[(new_index / 64) as usize] |= 1u64 << (new_index % 64);
+            }
+        }
+        remapped
+    }
+}
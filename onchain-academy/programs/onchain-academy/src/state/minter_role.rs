@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::AcademyError;
+
 pub const MAX_LABEL_LEN: usize = 32;
 
 #[account]
 pub struct MinterRole {
+    /// Account layout version (see `MinterRole::SCHEMA_VERSION`)
+    pub schema_version: u8,
     /// Wallet or program PDA authorized to mint XP
     pub minter: Pubkey,
     /// Human-readable label ("streak-program", "irl-events", etc.)
@@ -14,19 +18,95 @@ pub struct MinterRole {
     pub total_xp_minted: u64,
     pub is_active: bool,
     pub created_at: i64,
-    pub _reserved: [u8; 8],
+    /// Length of the rate-limit window in seconds. 0 = no window budget.
+    pub window_seconds: i64,
+    /// XP budget per window. 0 = unlimited.
+    pub max_xp_per_window: u64,
+    /// Start of the current window (set by the first mint after the previous one expired)
+    pub window_started_at: i64,
+    /// XP minted in the current window
+    pub window_xp_minted: u64,
+    /// Lifetime cap on `total_xp_minted`. 0 = unlimited.
+    pub max_total_xp: u64,
+    pub _reserved: [u8; 32],
     pub bump: u8,
 }
 
 impl MinterRole {
+    pub const SCHEMA_VERSION: u8 = 1;
+
     // 8 (discriminator)
+    // + 1 (schema_version)
     // + 32 (minter)
     // + (4 + 32) (label)
     // + 8 (max_xp_per_call)
     // + 8 (total_xp_minted)
     // + 1 (is_active)
     // + 8 (created_at)
-    // + 8 (_reserved)
+    // + 8 (window_seconds)
+    // + 8 (max_xp_per_window)
+    // + 8 (window_started_at)
+    // + 8 (window_xp_minted)
+    // + 8 (max_total_xp)
+    // + 32 (_reserved)
     // + 1 (bump)
-    pub const SIZE: usize = 8 + 32 + (4 + MAX_LABEL_LEN) + 8 + 8 + 1 + 8 + 8 + 1; // 110
+    pub const SIZE: usize =
+        8 + 1 + 32 + (4 + MAX_LABEL_LEN) + 8 + 8 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 32 + 1; // 175
+
+    /// Size of unversioned (schema 0) accounts, which predate rate limits;
+    /// see `migrate_minter_role`.
+    pub const LEGACY_SIZE: usize = 110;
+
+    /// Charges `amount` against the window budget and lifetime cap, then bumps
+    /// the counters. The window restarts once `window_seconds` have elapsed
+    /// since it opened.
+    pub fn record_mint(&mut self, amount: u64, now: i64) -> Result<()> {
+        let total = self
+            .total_xp_minted
+            .checked_add(amount)
+            .ok_or(AcademyError::Overflow)?;
+        if self.max_total_xp > 0 {
+            require!(
+                total <= self.max_total_xp,
+                AcademyError::MinterLifetimeCapExceeded
+            );
+        }
+
+        if self.window_seconds > 0 {
+            let elapsed = now.saturating_sub(self.window_started_at);
+            if elapsed >= self.window_seconds || elapsed < 0 {
+                self.window_started_at = now;
+                self.window_xp_minted = 0;
+            }
+        }
+
+        let window_total = self
+            .window_xp_minted
+            .checked_add(amount)
+            .ok_or(AcademyError::Overflow)?;
+        if self.max_xp_per_window > 0 {
+            require!(
+                window_total <= self.max_xp_per_window,
+                AcademyError::MinterWindowBudgetExceeded
+            );
+        }
+
+        self.window_xp_minted = window_total;
+        self.total_xp_minted = total;
+        Ok(())
+    }
+}
+
+/// Unversioned MinterRole layout (schema 0, no rate limits). Only used by
+/// `migrate_minter_role`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MinterRoleV0 {
+    pub minter: Pubkey,
+    pub label: String,
+    pub max_xp_per_call: u64,
+    pub total_xp_minted: u64,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub _reserved: [u8; 8],
+    pub bump: u8,
 }
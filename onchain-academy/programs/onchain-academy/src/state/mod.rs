@@ -1,11 +1,31 @@
 pub mod achievement_type;
+pub mod backend_signer;
+pub mod cohort;
+pub mod completion_receipt;
 pub mod config;
 pub mod course;
+pub mod course_author;
+pub mod course_lesson_xp;
+pub mod course_tombstone;
 pub mod enrollment;
+pub mod learner_profile;
+pub mod lesson_map;
 pub mod minter_role;
+pub mod track;
+pub mod track_credential;
 
 pub use achievement_type::*;
+pub use backend_signer::*;
+pub use cohort::*;
+pub use completion_receipt::*;
 pub use config::*;
 pub use course::*;
+pub use course_author::*;
+pub use course_lesson_xp::*;
+pub use course_tombstone::*;
 pub use enrollment::*;
+pub use learner_profile::*;
+pub use lesson_map::*;
 pub use minter_role::*;
+pub use track::*;
+pub use track_credential::*;
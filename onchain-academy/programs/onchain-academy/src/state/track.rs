@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_TRACK_NAME_LEN: usize = 32;
+pub const MAX_TRACK_LEVELS: usize = 8;
+pub const MAX_COURSES_PER_LEVEL: usize = 4;
+pub const MAX_TRACK_URI_LEN: usize = 128;
+
+/// Learning track registry. Seeds: ["track", track_id.to_le_bytes()]
+/// Credentials on the track are minted into `collection`, and a course's
+/// `track_level` must match the level it is listed under.
+#[account]
+pub struct Track {
+    /// Account layout version (see `Track::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    pub track_id: u16,
+    /// Metaplex Core collection (update authority = Config PDA)
+    pub collection: Pubkey,
+    pub name: String,
+    /// Credential metadata URI; `{level}` and `{learner}` are substituted
+    /// when a credential is issued or upgraded
+    pub uri_template: String,
+    /// Highest credential level; levels run from 1 to `max_level`
+    pub max_level: u8,
+    /// Courses per level in recommended order; `level_courses[i]` is level `i + 1`
+    pub level_courses: Vec<Vec<Pubkey>>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    /// Seconds a credential stays valid after issue or renewal (0 = never expires)
+    pub validity_period: i64,
+    /// Course whose completion renews an expiring credential (default = none)
+    pub recertification_course: Pubkey,
+    pub _reserved: [u8; 24],
+    pub bump: u8,
+}
+
+impl Track {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 2 (track_id)
+    // + 32 (collection)
+    // + (4 + 32) (name)
+    // + (4 + 128) (uri_template)
+    // + 1 (max_level)
+    // + (4 + 8 * (4 + 4 * 32)) (level_courses)
+    // + 8 (created_at)
+    // + 8 (updated_at)
+    // + 8 (validity_period)
+    // + 32 (recertification_course)
+    // + 24 (_reserved)
+    // + 1 (bump)
+    pub const SIZE: usize = 8
+        + 1
+        + 2
+        + 32
+        + (4 + MAX_TRACK_NAME_LEN)
+        + (4 + MAX_TRACK_URI_LEN)
+        + 1
+        + (4 + MAX_TRACK_LEVELS * (4 + MAX_COURSES_PER_LEVEL * 32))
+        + 8
+        + 8
+        + 8
+        + 32
+        + 24
+        + 1; // 1353
+
+    /// Whether `level` is a credential level on this track.
+    pub fn has_level(&self, level: u8) -> bool {
+        level >= 1 && level <= self.max_level
+    }
+
+    /// Level `course` is listed under, if any.
+    pub fn level_of(&self, course: &Pubkey) -> Option<u8> {
+        self.level_courses
+            .iter()
+            .position(|courses| courses.contains(course))
+            .map(|i| i as u8 + 1)
+    }
+
+    /// Whether credentials on this track can be renewed with `renew_credential`.
+    pub fn has_recertification(&self) -> bool {
+        self.validity_period > 0 && self.recertification_course != Pubkey::default()
+    }
+
+    /// Expiry of a credential certified at `certified_at` (0 = never).
+    pub fn credential_expiry(&self, certified_at: i64) -> i64 {
+        if self.validity_period == 0 {
+            0
+        } else {
+            certified_at.saturating_add(self.validity_period)
+        }
+    }
+
+    /// Credential asset name at `level`.
+    pub fn credential_name(&self, level: u8) -> String {
+        format!("{} - Level {}", self.name, level)
+    }
+
+    /// Credential metadata URI for `learner` at `level`.
+    pub fn credential_uri(&self, level: u8, learner: &Pubkey) -> String {
+        self.uri_template
+            .replace("{level}", &level.to_string())
+            .replace("{learner}", &learner.to_string())
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_REVOCATION_REASON_LEN: usize = 64;
+
+/// The learner's single credential on a track.
+/// Seeds: ["credential", track_id.to_le_bytes(), learner.key()]
+/// Created by the first `issue_credential` on the track; later completions on
+/// the same track upgrade `asset` in place.
+#[account]
+pub struct TrackCredential {
+    /// Account layout version (see `TrackCredential::SCHEMA_VERSION`)
+    pub schema_version: u8,
+    pub learner: Pubkey,
+    pub track_id: u16,
+    /// Soulbound Metaplex Core asset
+    pub asset: Pubkey,
+    /// Highest level credited so far
+    pub level: u8,
+    /// Completed enrollments issued into this credential
+    pub courses_credited: u16,
+    pub issued_at: i64,
+    pub updated_at: i64,
+    /// Set by `revoke_credential`; the next issue mints a fresh asset (0 = not revoked)
+    pub revoked_at: i64,
+    /// 0 = never expires
+    pub expires_at: i64,
+    /// Last `renew_credential` (0 = never renewed)
+    pub renewed_at: i64,
+    pub _reserved: [u8; 40],
+    pub bump: u8,
+}
+
+impl TrackCredential {
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    // 8 (discriminator)
+    // + 1 (schema_version)
+    // + 32 (learner)
+    // + 2 (track_id)
+    // + 32 (asset)
+    // + 1 (level)
+    // + 2 (courses_credited)
+    // + 8 (issued_at)
+    // + 8 (updated_at)
+    // + 8 (revoked_at)
+    // + 8 (expires_at)
+    // + 8 (renewed_at)
+    // + 40 (_reserved)
+    // + 1 (bump)
+    pub const SIZE: usize = 8 + 1 + 32 + 2 + 32 + 1 + 2 + 8 + 8 + 8 + 8 + 8 + 40 + 1; // 159
+
+    /// Whether the account was just allocated by `init_if_needed`.
+    pub fn is_new(&self) -> bool {
+        self.asset == Pubkey::default()
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at != 0
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+
+    /// Time of the last issue or renewal; recertification must complete after it.
+    pub fn certified_at(&self) -> i64 {
+        self.issued_at.max(self.renewed_at)
+    }
+
+    pub fn status(&self, now: i64) -> CredentialStatus {
+        CredentialStatus {
+            asset: self.asset,
+            track_id: self.track_id,
+            level: self.level,
+            issued_at: self.issued_at,
+            expires_at: self.expires_at,
+            revoked: self.is_revoked(),
+            expired: self.is_expired(now),
+        }
+    }
+}
+
+/// Returned by `verify_credential`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CredentialStatus {
+    pub asset: Pubkey,
+    pub track_id: u16,
+    pub level: u8,
+    pub issued_at: i64,
+    /// 0 = never expires
+    pub expires_at: i64,
+    pub revoked: bool,
+    pub expired: bool,
+}
@@ -1,5 +1,22 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_plugin,
+    instructions::{CreateV2CpiBuilder, UpdatePluginV1CpiBuilder, UpdateV1CpiBuilder},
+    types::{
+        Attribute, Attributes, Key as AssetKey, PermanentFreezeDelegate, Plugin, PluginAuthority,
+        PluginAuthorityPair, PluginType, UpdateAuthority,
+    },
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount};
+
+use crate::errors::AcademyError;
+use crate::state::{BackendSigner, Config};
 
 /// Mints XP tokens via Token-2022 CPI. The authority (Config PDA) signs
 /// using the provided seeds.
@@ -28,3 +45,355 @@ pub fn mint_xp<'info>(
 
     Ok(())
 }
+
+/// True if `signer` may act as backend for `scope`: either it is
+/// `config.backend_signer` (all scopes), or `role` is its registry entry,
+/// grants `scope` and has not expired.
+pub fn is_backend_signer(
+    config: &Config,
+    role: Option<&BackendSigner>,
+    signer: &Pubkey,
+    scope: u8,
+) -> Result<bool> {
+    if *signer == config.backend_signer {
+        return Ok(true);
+    }
+    let Some(role) = role else {
+        return Ok(false);
+    };
+    Ok(role.signer == *signer && role.allows(scope, Clock::get()?.unix_timestamp))
+}
+
+/// Verifies that `collection_info` is a Metaplex Core collection whose update
+/// authority is the Config PDA, i.e. one the program can mint credentials into.
+pub fn require_academy_collection(
+    collection_info: &AccountInfo,
+    config_key: &Pubkey,
+) -> Result<()> {
+    require!(
+        collection_info.owner == &mpl_core::ID,
+        AcademyError::InvalidTrack
+    );
+    let collection = BaseCollectionV1::from_bytes(&collection_info.try_borrow_data()?)
+        .map_err(|_| AcademyError::InvalidTrack)?;
+    require!(
+        collection.key == AssetKey::CollectionV1 && collection.update_authority == *config_key,
+        AcademyError::InvalidTrack
+    );
+    Ok(())
+}
+
+/// Attribute set on revoked credentials and achievements; its value is the
+/// revocation reason.
+pub const REVOKED_ATTRIBUTE: &str = "revoked";
+
+/// Attributes plugin written to every track credential. `expires_at` is only
+/// written for credentials that expire.
+pub fn credential_attributes(
+    track_id: u16,
+    level: u8,
+    courses_completed: u32,
+    total_xp: u64,
+    issued_at: i64,
+    expires_at: i64,
+) -> Attributes {
+    let attribute = |key: &str, value: String| Attribute {
+        key: key.into(),
+        value,
+    };
+    let mut attribute_list = vec![
+        attribute("track_id", track_id.to_string()),
+        attribute("level", level.to_string()),
+        attribute("courses_completed", courses_completed.to_string()),
+        attribute("total_xp", total_xp.to_string()),
+        attribute("issued_at", issued_at.to_string()),
+    ];
+    if expires_at != 0 {
+        attribute_list.push(attribute("expires_at", expires_at.to_string()));
+    }
+    Attributes { attribute_list }
+}
+
+/// Accounts shared by the credential and achievement CPIs. `config` is the
+/// collection's update authority and signs with `config_seeds`.
+pub struct CredentialCpi<'a, 'info> {
+    pub mpl_core_program: &'a AccountInfo<'info>,
+    pub asset: &'a AccountInfo<'info>,
+    pub collection: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub config_seeds: &'a [&'a [u8]],
+}
+
+impl<'info> CredentialCpi<'_, 'info> {
+    /// Mints a soulbound (permanently frozen) credential to `owner`.
+    pub fn create(
+        &self,
+        owner: &AccountInfo<'info>,
+        name: String,
+        uri: String,
+        attributes: Attributes,
+    ) -> Result<()> {
+        CreateV2CpiBuilder::new(self.mpl_core_program)
+            .asset(self.asset)
+            .collection(Some(self.collection))
+            .payer(self.payer)
+            .owner(Some(owner))
+            .authority(Some(self.config))
+            .system_program(self.system_program)
+            .name(name)
+            .uri(uri)
+            .plugins(vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate {
+                        frozen: true,
+                    }),
+                    authority: Some(PluginAuthority::UpdateAuthority),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::Attributes(attributes),
+                    authority: Some(PluginAuthority::UpdateAuthority),
+                },
+            ])
+            .invoke_signed(&[self.config_seeds])?;
+        Ok(())
+    }
+
+    /// Adds the `revoked` attribute to an existing asset, keeping its other
+    /// attributes. The asset stays frozen in the owner's wallet.
+    pub fn revoke(&self, reason: &str) -> Result<()> {
+        let (_, mut attributes, _) =
+            fetch_plugin::<BaseAssetV1, Attributes>(self.asset, PluginType::Attributes)
+                .map_err(|_| AcademyError::InvalidCredentialAsset)?;
+        require!(
+            !attributes
+                .attribute_list
+                .iter()
+                .any(|attribute| attribute.key == REVOKED_ATTRIBUTE),
+            AcademyError::AlreadyRevoked
+        );
+        attributes.attribute_list.push(Attribute {
+            key: REVOKED_ATTRIBUTE.into(),
+            value: reason.into(),
+        });
+
+        UpdatePluginV1CpiBuilder::new(self.mpl_core_program)
+            .asset(self.asset)
+            .collection(Some(self.collection))
+            .authority(Some(self.config))
+            .payer(self.payer)
+            .system_program(self.system_program)
+            .plugin(Plugin::Attributes(attributes))
+            .invoke_signed(&[self.config_seeds])?;
+        Ok(())
+    }
+
+    /// Rewrites an existing credential's name, URI and attributes.
+    pub fn update(&self, name: String, uri: String, attributes: Attributes) -> Result<()> {
+        UpdateV1CpiBuilder::new(self.mpl_core_program)
+            .asset(self.asset)
+            .collection(Some(self.collection))
+            .authority(Some(self.config))
+            .payer(self.payer)
+            .system_program(self.system_program)
+            .new_name(name)
+            .new_uri(uri)
+            .invoke_signed(&[self.config_seeds])?;
+
+        UpdatePluginV1CpiBuilder::new(self.mpl_core_program)
+            .asset(self.asset)
+            .collection(Some(self.collection))
+            .authority(Some(self.config))
+            .payer(self.payer)
+            .system_program(self.system_program)
+            .plugin(Plugin::Attributes(attributes))
+            .invoke_signed(&[self.config_seeds])?;
+        Ok(())
+    }
+}
+
+/// Reads an academy credential (a Metaplex Core asset owned by `owner` in a
+/// collection whose update authority is the Config PDA) and returns its
+/// `track_id`, `level` and `expires_at` (0 = never) attributes.
+pub fn read_credential_track(
+    asset_info: &AccountInfo,
+    collection_info: &AccountInfo,
+    config_key: &Pubkey,
+    owner: &Pubkey,
+) -> Result<(u16, u8, i64)> {
+    require!(
+        asset_info.owner == &mpl_core::ID && collection_info.owner == &mpl_core::ID,
+        AcademyError::InvalidCredentialAsset
+    );
+    let asset = BaseAssetV1::from_bytes(&asset_info.try_borrow_data()?)
+        .map_err(|_| AcademyError::InvalidCredentialAsset)?;
+    let collection = BaseCollectionV1::from_bytes(&collection_info.try_borrow_data()?)
+        .map_err(|_| AcademyError::InvalidCredentialAsset)?;
+    require!(
+        asset.key == AssetKey::AssetV1
+            && asset.owner == *owner
+            && asset.update_authority == UpdateAuthority::Collection(collection_info.key())
+            && collection.key == AssetKey::CollectionV1
+            && collection.update_authority == *config_key,
+        AcademyError::InvalidCredentialAsset
+    );
+
+    let (_, attributes, _) =
+        fetch_plugin::<BaseAssetV1, Attributes>(asset_info, PluginType::Attributes)
+            .map_err(|_| AcademyError::InvalidCredentialAsset)?;
+    let attribute = |key: &str| {
+        attributes
+            .attribute_list
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+    };
+    require!(
+        attribute(REVOKED_ATTRIBUTE).is_none(),
+        AcademyError::InvalidCredentialAsset
+    );
+    let track_id = attribute("track_id").and_then(|value| value.parse().ok());
+    let level = attribute("level").and_then(|value| value.parse().ok());
+    let expires_at = match attribute("expires_at") {
+        Some(value) => value.parse().ok(),
+        None => Some(0),
+    };
+    match (track_id, level, expires_at) {
+        (Some(track_id), Some(level), Some(expires_at)) => Ok((track_id, level, expires_at)),
+        _ => err!(AcademyError::InvalidCredentialAsset),
+    }
+}
+
+/// Reads the Ed25519 precompile instruction immediately preceding the current
+/// one and returns the signing key and signed message. The runtime has already
+/// verified the signature if the transaction got this far.
+pub fn load_ed25519_attestation(instructions_sysvar: &AccountInfo) -> Result<(Pubkey, Vec<u8>)> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    require!(current > 0, AcademyError::InvalidAttestation);
+    let ix = load_instruction_at_checked(current as usize - 1, instructions_sysvar)?;
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        AcademyError::InvalidAttestation
+    );
+    let (signer, message) = parse_ed25519_instruction(&ix.data)?;
+    Ok((signer, message.to_vec()))
+}
+
+/// Parses Ed25519 precompile instruction data carrying exactly one signature
+/// whose key, signature and message all live in the same instruction.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Result<(Pubkey, &[u8])> {
+    // [num_signatures: u8, padding: u8] then 7 u16 offsets per signature
+    const HEADER_LEN: usize = 2 + 14;
+    // Instruction index meaning "this instruction"
+    const CURRENT_IX: u16 = u16::MAX;
+
+    require!(
+        data.len() >= HEADER_LEN && data[0] == 1,
+        AcademyError::InvalidAttestation
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let signature_ix = read_u16(4);
+    let public_key_offset = read_u16(6) as usize;
+    let public_key_ix = read_u16(8);
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+    let message_ix = read_u16(14);
+
+    require!(
+        signature_ix == CURRENT_IX && public_key_ix == CURRENT_IX && message_ix == CURRENT_IX,
+        AcademyError::InvalidAttestation
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(AcademyError::InvalidAttestation)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(AcademyError::InvalidAttestation)?;
+
+    let signer = Pubkey::try_from(public_key).map_err(|_| AcademyError::InvalidAttestation)?;
+    Ok((signer, message))
+}
+
+/// Verifies that `token_account` is an XP token account owned by `owner`, so
+/// XP credited to a learner's profile cannot be routed to someone else's wallet.
+pub fn require_xp_token_account(
+    token_account: &AccountInfo,
+    xp_mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    xp_token_balance(token_account, xp_mint, owner).map(|_| ())
+}
+
+/// Balance of `owner`'s XP token account, after the same checks as
+/// `require_xp_token_account`. XP is non-transferable, so this includes XP
+/// minted before the wallet had a `LearnerProfile`.
+pub fn xp_token_balance(
+    token_account: &AccountInfo,
+    xp_mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<u64> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<TokenAccount>::unpack(&data)
+        .map_err(|_| AcademyError::TokenAccountMismatch)?;
+    require_keys_eq!(
+        account.base.mint,
+        *xp_mint,
+        AcademyError::TokenAccountMismatch
+    );
+    require_keys_eq!(
+        account.base.owner,
+        *owner,
+        AcademyError::TokenAccountMismatch
+    );
+    Ok(account.base.amount)
+}
+
+/// Reads an unversioned (schema 0) account body. The account must still be
+/// exactly `legacy_size` bytes and carry `discriminator`; anything else is
+/// either already migrated or not the expected account type.
+pub fn read_legacy_account<T: AnchorDeserialize>(
+    info: &AccountInfo,
+    discriminator: &[u8],
+    legacy_size: usize,
+) -> Result<T> {
+    require!(
+        info.data_len() == legacy_size,
+        AcademyError::InvalidMigrationSource
+    );
+    let data = info.try_borrow_data()?;
+    require!(
+        &data[..discriminator.len()] == discriminator,
+        AcademyError::InvalidMigrationSource
+    );
+    T::deserialize(&mut &data[discriminator.len()..])
+        .map_err(|_| error!(AcademyError::InvalidMigrationSource))
+}
+
+/// Reallocs `info` to `new_size`, topping up rent from `payer` first.
+pub fn grow_account<'info>(
+    info: &AccountInfo<'info>,
+    new_size: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent_due = Rent::get()?
+        .minimum_balance(new_size)
+        .saturating_sub(info.lamports());
+    if rent_due > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    info.realloc(new_size, false)?;
+    Ok(())
+}
@@ -61,8 +61,7 @@ describe("onchain-academy", () => {
 
   const XP_PER_LESSON = 100;
   const LESSON_COUNT = 3;
-  // Completion bonus is now 50% of total lesson XP = (XP_PER_LESSON * LESSON_COUNT) / 2
-  const EXPECTED_BONUS_XP = Math.floor((XP_PER_LESSON * LESSON_COUNT) / 2);
+  const COMPLETION_BONUS_XP = 150;
   const CREATOR_REWARD_XP = 50;
   const MIN_COMPLETIONS_FOR_REWARD = 1;
 
@@ -184,6 +183,8 @@ describe("onchain-academy", () => {
       await program.methods
         .updateConfig({
           newBackendSigner: newSigner.publicKey,
+          newDailyXpCap: null,
+          newGuardian: null,
         })
         .accountsPartial({
           config: configPda,
@@ -200,6 +201,8 @@ describe("onchain-academy", () => {
       await program.methods
         .updateConfig({
           newBackendSigner: authority.publicKey,
+          newDailyXpCap: null,
+          newGuardian: null,
         })
         .accountsPartial({
           config: configPda,
@@ -219,6 +222,8 @@ describe("onchain-academy", () => {
       await program.methods
         .updateConfig({
           newBackendSigner: null,
+          newDailyXpCap: null,
+          newGuardian: null,
         })
         .accountsPartial({
           config: configPda,
@@ -244,6 +249,8 @@ describe("onchain-academy", () => {
         await program.methods
           .updateConfig({
             newBackendSigner: imposter.publicKey,
+            newDailyXpCap: null,
+            newGuardian: null,
           })
           .accountsPartial({
             config: configPda,
@@ -277,9 +284,18 @@ describe("onchain-academy", () => {
           xpPerLesson: XP_PER_LESSON,
           trackId: 1,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: CREATOR_REWARD_XP,
           minCompletionsForReward: MIN_COMPLETIONS_FOR_REWARD,
+          completionBonusXp: COMPLETION_BONUS_XP,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: coursePda,
@@ -306,6 +322,7 @@ describe("onchain-academy", () => {
       expect(course.minCompletionsForReward).to.equal(
         MIN_COMPLETIONS_FOR_REWARD
       );
+      expect(course.completionBonusXp).to.equal(COMPLETION_BONUS_XP);
       expect(course.totalCompletions).to.equal(0);
       expect(course.totalEnrollments).to.equal(0);
       expect(course.isActive).to.equal(true);
@@ -331,9 +348,18 @@ describe("onchain-academy", () => {
             xpPerLesson: 10,
             trackId: 1,
             trackLevel: 1,
-            prerequisite: null,
+            prerequisites: [],
+            prerequisiteMinCount: 0,
             creatorRewardXp: 0,
             minCompletionsForReward: 0,
+            completionBonusXp: 5,
+            completionBonusBps: 0,
+            enrollOpensAt: new BN(0),
+            enrollClosesAt: new BN(0),
+            completionDeadline: new BN(0),
+            maxEnrollments: 0,
+            cohortOnly: false,
+            progressExpiresAfter: new BN(0),
           })
           .accountsPartial({
             course: emptyPda,
@@ -371,9 +397,18 @@ describe("onchain-academy", () => {
             xpPerLesson: 10,
             trackId: 1,
             trackLevel: 1,
-            prerequisite: null,
+            prerequisites: [],
+            prerequisiteMinCount: 0,
             creatorRewardXp: 0,
             minCompletionsForReward: 0,
+            completionBonusXp: 5,
+            completionBonusBps: 0,
+            enrollOpensAt: new BN(0),
+            enrollClosesAt: new BN(0),
+            completionDeadline: new BN(0),
+            maxEnrollments: 0,
+            cohortOnly: false,
+            progressExpiresAfter: new BN(0),
           })
           .accountsPartial({
             course: longPda,
@@ -411,9 +446,18 @@ describe("onchain-academy", () => {
             xpPerLesson: 10,
             trackId: 1,
             trackLevel: 1,
-            prerequisite: null,
+            prerequisites: [],
+            prerequisiteMinCount: 0,
             creatorRewardXp: 0,
             minCompletionsForReward: 0,
+            completionBonusXp: 5,
+            completionBonusBps: 0,
+            enrollOpensAt: new BN(0),
+            enrollClosesAt: new BN(0),
+            completionDeadline: new BN(0),
+            maxEnrollments: 0,
+            cohortOnly: false,
+            progressExpiresAfter: new BN(0),
           })
           .accountsPartial({
             course: badPda,
@@ -450,9 +494,18 @@ describe("onchain-academy", () => {
             xpPerLesson: 10,
             trackId: 1,
             trackLevel: 1,
-            prerequisite: null,
+            prerequisites: [],
+            prerequisiteMinCount: 0,
             creatorRewardXp: 0,
             minCompletionsForReward: 0,
+            completionBonusXp: 5,
+            completionBonusBps: 0,
+            enrollOpensAt: new BN(0),
+            enrollClosesAt: new BN(0),
+            completionDeadline: new BN(0),
+            maxEnrollments: 0,
+            cohortOnly: false,
+            progressExpiresAfter: new BN(0),
           })
           .accountsPartial({
             course: badPda,
@@ -489,9 +542,18 @@ describe("onchain-academy", () => {
             xpPerLesson: 10,
             trackId: 1,
             trackLevel: 1,
-            prerequisite: null,
+            prerequisites: [],
+            prerequisiteMinCount: 0,
             creatorRewardXp: 0,
             minCompletionsForReward: 0,
+            completionBonusXp: 0,
+            completionBonusBps: 0,
+            enrollOpensAt: new BN(0),
+            enrollClosesAt: new BN(0),
+            completionDeadline: new BN(0),
+            maxEnrollments: 0,
+            cohortOnly: false,
+            progressExpiresAfter: new BN(0),
           })
           .accountsPartial({
             course: badPda,
@@ -527,9 +589,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 10,
           trackId: 1,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 0,
           minCompletionsForReward: 0,
+          completionBonusXp: 5,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: maxPda,
@@ -562,9 +633,18 @@ describe("onchain-academy", () => {
             xpPerLesson: 10,
             trackId: 10,
             trackLevel: difficulty,
-            prerequisite: null,
+            prerequisites: [],
+            prerequisiteMinCount: 0,
             creatorRewardXp: 0,
             minCompletionsForReward: 0,
+            completionBonusXp: 5,
+            completionBonusBps: 0,
+            enrollOpensAt: new BN(0),
+            enrollClosesAt: new BN(0),
+            completionDeadline: new BN(0),
+            maxEnrollments: 0,
+            cohortOnly: false,
+            progressExpiresAfter: new BN(0),
           })
           .accountsPartial({
             course: diffPda,
@@ -594,6 +674,22 @@ describe("onchain-academy", () => {
           newXpPerLesson: null,
           newCreatorRewardXp: null,
           newMinCompletionsForReward: null,
+          newCompletionBonusXp: null,
+          newCompletionBonusBps: null,
+          newPrerequisites: null,
+          newPrerequisiteMinCount: null,
+          newEnrollOpensAt: null,
+          newEnrollClosesAt: null,
+          newCompletionDeadline: null,
+          newMaxEnrollments: null,
+          newCohortOnly: null,
+          newProgressExpiresAfter: null,
+          newLessonCount: null,
+          newDifficulty: null,
+          newTrackId: null,
+          newTrackLevel: null,
+          newCreator: null,
+          newAuthor: null,
         })
         .accountsPartial({
           course: coursePda,
@@ -615,6 +711,22 @@ describe("onchain-academy", () => {
           newXpPerLesson: null,
           newCreatorRewardXp: null,
           newMinCompletionsForReward: null,
+          newCompletionBonusXp: null,
+          newCompletionBonusBps: null,
+          newPrerequisites: null,
+          newPrerequisiteMinCount: null,
+          newEnrollOpensAt: null,
+          newEnrollClosesAt: null,
+          newCompletionDeadline: null,
+          newMaxEnrollments: null,
+          newCohortOnly: null,
+          newProgressExpiresAfter: null,
+          newLessonCount: null,
+          newDifficulty: null,
+          newTrackId: null,
+          newTrackLevel: null,
+          newCreator: null,
+          newAuthor: null,
         })
         .accountsPartial({
           course: coursePda,
@@ -634,6 +746,22 @@ describe("onchain-academy", () => {
           newXpPerLesson: null,
           newCreatorRewardXp: null,
           newMinCompletionsForReward: null,
+          newCompletionBonusXp: null,
+          newCompletionBonusBps: null,
+          newPrerequisites: null,
+          newPrerequisiteMinCount: null,
+          newEnrollOpensAt: null,
+          newEnrollClosesAt: null,
+          newCompletionDeadline: null,
+          newMaxEnrollments: null,
+          newCohortOnly: null,
+          newProgressExpiresAfter: null,
+          newLessonCount: null,
+          newDifficulty: null,
+          newTrackId: null,
+          newTrackLevel: null,
+          newCreator: null,
+          newAuthor: null,
         })
         .accountsPartial({
           course: coursePda,
@@ -661,6 +789,22 @@ describe("onchain-academy", () => {
           newXpPerLesson: 200,
           newCreatorRewardXp: 50,
           newMinCompletionsForReward: 5,
+          newCompletionBonusXp: null,
+          newCompletionBonusBps: null,
+          newPrerequisites: null,
+          newPrerequisiteMinCount: null,
+          newEnrollOpensAt: null,
+          newEnrollClosesAt: null,
+          newCompletionDeadline: null,
+          newMaxEnrollments: null,
+          newCohortOnly: null,
+          newProgressExpiresAfter: null,
+          newLessonCount: null,
+          newDifficulty: null,
+          newTrackId: null,
+          newTrackLevel: null,
+          newCreator: null,
+          newAuthor: null,
         })
         .accountsPartial({
           course: diffPda,
@@ -693,6 +837,22 @@ describe("onchain-academy", () => {
             newXpPerLesson: null,
             newCreatorRewardXp: null,
             newMinCompletionsForReward: null,
+            newCompletionBonusXp: null,
+            newCompletionBonusBps: null,
+            newPrerequisites: null,
+            newPrerequisiteMinCount: null,
+            newEnrollOpensAt: null,
+            newEnrollClosesAt: null,
+            newCompletionDeadline: null,
+            newMaxEnrollments: null,
+            newCohortOnly: null,
+            newProgressExpiresAfter: null,
+            newLessonCount: null,
+            newDifficulty: null,
+            newTrackId: null,
+            newTrackLevel: null,
+            newCreator: null,
+            newAuthor: null,
           })
           .accountsPartial({
             course: coursePda,
@@ -797,6 +957,22 @@ describe("onchain-academy", () => {
           newXpPerLesson: null,
           newCreatorRewardXp: null,
           newMinCompletionsForReward: null,
+          newCompletionBonusXp: null,
+          newCompletionBonusBps: null,
+          newPrerequisites: null,
+          newPrerequisiteMinCount: null,
+          newEnrollOpensAt: null,
+          newEnrollClosesAt: null,
+          newCompletionDeadline: null,
+          newMaxEnrollments: null,
+          newCohortOnly: null,
+          newProgressExpiresAfter: null,
+          newLessonCount: null,
+          newDifficulty: null,
+          newTrackId: null,
+          newTrackLevel: null,
+          newCreator: null,
+          newAuthor: null,
         })
         .accountsPartial({
           course: coursePda,
@@ -849,6 +1025,22 @@ describe("onchain-academy", () => {
           newXpPerLesson: null,
           newCreatorRewardXp: null,
           newMinCompletionsForReward: null,
+          newCompletionBonusXp: null,
+          newCompletionBonusBps: null,
+          newPrerequisites: null,
+          newPrerequisiteMinCount: null,
+          newEnrollOpensAt: null,
+          newEnrollClosesAt: null,
+          newCompletionDeadline: null,
+          newMaxEnrollments: null,
+          newCohortOnly: null,
+          newProgressExpiresAfter: null,
+          newLessonCount: null,
+          newDifficulty: null,
+          newTrackId: null,
+          newTrackLevel: null,
+          newCreator: null,
+          newAuthor: null,
         })
         .accountsPartial({
           course: coursePda,
@@ -1050,7 +1242,7 @@ describe("onchain-academy", () => {
       const course = await program.account.course.fetch(coursePda);
       expect(course.totalCompletions).to.equal(1);
 
-      // Learner XP: 3*100 (lessons) + 150 (bonus = floor(100*3/2)) = 450
+      // Learner XP: 3*100 (lessons) + 150 (bonus) = 450
       const learnerAta = await getAccount(
         provider.connection,
         learnerTokenAccount,
@@ -1058,7 +1250,7 @@ describe("onchain-academy", () => {
         TOKEN_2022_PROGRAM_ID
       );
       expect(Number(learnerAta.amount)).to.equal(
-        XP_PER_LESSON * LESSON_COUNT + EXPECTED_BONUS_XP
+        XP_PER_LESSON * LESSON_COUNT + COMPLETION_BONUS_XP
       );
 
       // Creator XP: 50 (reward met since totalCompletions=1 >= minCompletionsForReward=1)
@@ -1115,9 +1307,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 10,
           trackId: 2,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 10,
           minCompletionsForReward: 1,
+          completionBonusXp: 25,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: incompletePda,
@@ -1197,12 +1398,21 @@ describe("onchain-academy", () => {
       const balanceBefore = await provider.connection.getBalance(
         learner.publicKey
       );
+      const [receiptPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("completion"),
+          coursePda.toBuffer(),
+          learner.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
 
       await program.methods
         .closeEnrollment()
         .accountsPartial({
           course: coursePda,
           enrollment: enrollmentPda,
+          completionReceipt: receiptPda,
           learner: learner.publicKey,
         })
         .signers([learner])
@@ -1213,6 +1423,13 @@ describe("onchain-academy", () => {
       );
       expect(enrollmentInfo).to.be.null;
 
+      // Completed enrollments leave a receipt behind
+      const receipt = await program.account.completionReceipt.fetch(
+        receiptPda
+      );
+      expect(receipt.course.toBase58()).to.equal(coursePda.toBase58());
+      expect(receipt.completedAt.toNumber()).to.be.greaterThan(0);
+
       const balanceAfter = await provider.connection.getBalance(
         learner.publicKey
       );
@@ -1236,9 +1453,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 10,
           trackId: 3,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 0,
           minCompletionsForReward: 0,
+          completionBonusXp: 15,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: freshCoursePda,
@@ -1397,7 +1623,7 @@ describe("onchain-academy", () => {
       // (the inactive-course test used a different learner that failed, no init)
       expect(course.totalEnrollments).to.equal(2);
 
-      // Learner2 XP: 300 (lessons) + 150 (bonus = floor(100*3/2)) = 450
+      // Learner2 XP: 300 (lessons) + 150 (bonus) = 450
       const l2ata = await getAccount(
         provider.connection,
         learner2TokenAccount,
@@ -1405,7 +1631,7 @@ describe("onchain-academy", () => {
         TOKEN_2022_PROGRAM_ID
       );
       expect(Number(l2ata.amount)).to.equal(
-        XP_PER_LESSON * LESSON_COUNT + EXPECTED_BONUS_XP
+        XP_PER_LESSON * LESSON_COUNT + COMPLETION_BONUS_XP
       );
 
       // Creator XP should now be 50 + 50 = 100 (reward for both completions)
@@ -1419,11 +1645,21 @@ describe("onchain-academy", () => {
     });
 
     it("second learner can close their completed enrollment", async () => {
+      const [receiptPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("completion"),
+          coursePda.toBuffer(),
+          learner2.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
       await program.methods
         .closeEnrollment()
         .accountsPartial({
           course: coursePda,
           enrollment: learner2EnrollPda,
+          completionReceipt: receiptPda,
           learner: learner2.publicKey,
         })
         .signers([learner2])
@@ -1487,9 +1723,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 10,
           trackId: 5,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 0,
           minCompletionsForReward: 0,
+          completionBonusXp: 10,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: otherCoursePda,
@@ -1616,9 +1861,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 50,
           trackId: 7,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 100,
           minCompletionsForReward: 10,
+          completionBonusXp: 25,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: threshCoursePda,
@@ -1778,9 +2032,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 200,
           trackId: 1,
           trackLevel: 2,
-          prerequisite: coursePda, // requires solana-101
+          prerequisites: [coursePda], // requires solana-101
+          prerequisiteMinCount: 0,
           creatorRewardXp: 0,
           minCompletionsForReward: 0,
+          completionBonusXp: 100,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: advancedCoursePda,
@@ -2005,6 +2268,17 @@ describe("onchain-academy", () => {
     let credEnrollPda: PublicKey;
     let collectionAddress: PublicKey;
     let credentialKeypair: Keypair;
+    let credLearnerProfilePda: PublicKey;
+    const credTrackId = 1;
+    const credUriTemplate = "https://arweave.net/track-1/{level}/{learner}";
+    let credTrackPda: PublicKey;
+    let credTrackCredentialPda: PublicKey;
+
+    const trackIdSeed = (trackId: number) => {
+      const buf = Buffer.alloc(2);
+      buf.writeUInt16LE(trackId);
+      return buf;
+    };
 
     before(async () => {
       // Airdrop to credential learner
@@ -2032,6 +2306,43 @@ describe("onchain-academy", () => {
 
       collectionAddress = toWeb3JsPublicKey(collectionSigner.publicKey);
 
+      [credLearnerProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("learner"), credLearner.publicKey.toBuffer()],
+        program.programId
+      );
+
+      // Register track 1 against the collection
+      [credTrackPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("track"), trackIdSeed(credTrackId)],
+        program.programId
+      );
+      [credTrackCredentialPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("credential"),
+          trackIdSeed(credTrackId),
+          credLearner.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createTrack({
+          trackId: credTrackId,
+          name: "Solana Track",
+          uriTemplate: credUriTemplate,
+          maxLevel: 3,
+          validityPeriod: new BN(0),
+          recertificationCourse: PublicKey.default,
+        })
+        .accountsPartial({
+          config: configPda,
+          track: credTrackPda,
+          collection: collectionAddress,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
       // Create course for credential tests
       [credCoursePda] = PublicKey.findProgramAddressSync(
         [Buffer.from("course"), Buffer.from(credCourseId)],
@@ -2048,9 +2359,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 50,
           trackId: 1,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 0,
           minCompletionsForReward: 0,
+          completionBonusXp: 50,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: credCoursePda,
@@ -2060,6 +2380,19 @@ describe("onchain-academy", () => {
         })
         .rpc();
 
+      // List the course as track 1, level 1
+      await program.methods
+        .setTrackCourses(1, [credCoursePda])
+        .accountsPartial({
+          config: configPda,
+          track: credTrackPda,
+          authority: authority.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: credCoursePda, isWritable: false, isSigner: false },
+        ])
+        .rpc();
+
       // Create ATA for credential learner
       credLearnerTokenAccount = getAssociatedTokenAddressSync(
         xpMintKeypair.publicKey,
@@ -2142,11 +2475,14 @@ describe("onchain-academy", () => {
       credentialKeypair = Keypair.generate();
 
       const sig = await program.methods
-        .issueCredential("Solana Track - Level 1", "https://arweave.net/cred-metadata-v1", 1, new anchor.BN(500))
+        .issueCredential()
         .accountsPartial({
           config: configPda,
           course: credCoursePda,
           enrollment: credEnrollPda,
+          learnerProfile: credLearnerProfilePda,
+          track: credTrackPda,
+          trackCredential: credTrackCredentialPda,
           learner: credLearner.publicKey,
           credentialAsset: credentialKeypair.publicKey,
           trackCollection: collectionAddress,
@@ -2180,8 +2516,11 @@ describe("onchain-academy", () => {
         fromWeb3JsPublicKey(credentialKeypair.publicKey)
       );
 
+      // Name and URI are derived from the track
       expect(asset.name).to.equal("Solana Track - Level 1");
-      expect(asset.uri).to.equal("https://arweave.net/cred-metadata-v1");
+      expect(asset.uri).to.equal(
+        `https://arweave.net/track-1/1/${credLearner.publicKey.toBase58()}`
+      );
       expect(asset.owner.toString()).to.equal(
         fromWeb3JsPublicKey(credLearner.publicKey).toString()
       );
@@ -2201,7 +2540,20 @@ describe("onchain-academy", () => {
       expect(trackIdAttr.value).to.equal("1");
       expect(levelAttr.value).to.equal("1");
       expect(coursesAttr.value).to.equal("1");
-      expect(xpAttr.value).to.equal("500"); // Passed as param from backend
+      // Read from the learner's profile
+      const profile = await program.account.learnerProfile.fetch(
+        credLearnerProfilePda
+      );
+      expect(xpAttr.value).to.equal(profile.totalXp.toString());
+
+      const trackCredential = await program.account.trackCredential.fetch(
+        credTrackCredentialPda
+      );
+      expect(trackCredential.asset.toBase58()).to.equal(
+        credentialKeypair.publicKey.toBase58()
+      );
+      expect(trackCredential.level).to.equal(1);
+      expect(trackCredential.coursesCredited).to.equal(1);
     });
 
     it("fails to issue credential for unfinalized enrollment", async () => {
@@ -2222,6 +2574,19 @@ describe("onchain-academy", () => {
         program.programId
       );
 
+      const [unfinalizedProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("learner"), unfinalizedLearner.publicKey.toBuffer()],
+        program.programId
+      );
+      const [unfinalizedCredentialPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("credential"),
+          trackIdSeed(credTrackId),
+          unfinalizedLearner.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
       await program.methods
         .enroll(credCourseId)
         .accountsPartial({
@@ -2237,11 +2602,14 @@ describe("onchain-academy", () => {
 
       try {
         await program.methods
-          .issueCredential("Should Fail", "https://arweave.net/fail", 1, new anchor.BN(500))
+          .issueCredential()
           .accountsPartial({
             config: configPda,
             course: credCoursePda,
             enrollment: unfinalizedEnrollPda,
+            learnerProfile: unfinalizedProfilePda,
+            track: credTrackPda,
+            trackCredential: unfinalizedCredentialPda,
             learner: unfinalizedLearner.publicKey,
             credentialAsset: newAssetKeypair.publicKey,
             trackCollection: collectionAddress,
@@ -2266,11 +2634,14 @@ describe("onchain-academy", () => {
       const anotherAsset = Keypair.generate();
       try {
         await program.methods
-          .issueCredential("Duplicate", "https://arweave.net/dup", 1, new anchor.BN(500))
+          .issueCredential()
           .accountsPartial({
             config: configPda,
             course: credCoursePda,
             enrollment: credEnrollPda,
+            learnerProfile: credLearnerProfilePda,
+            track: credTrackPda,
+            trackCredential: credTrackCredentialPda,
             learner: credLearner.publicKey,
             credentialAsset: anotherAsset.publicKey,
             trackCollection: collectionAddress,
@@ -2291,13 +2662,210 @@ describe("onchain-academy", () => {
       }
     });
 
+    it("issues credential from a completion receipt after close_enrollment", async () => {
+      const receiptLearner = Keypair.generate();
+      const airdropSig = await provider.connection.requestAirdrop(
+        receiptLearner.publicKey,
+        5 * LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig, "confirmed");
+
+      const receiptLearnerTokenAccount = getAssociatedTokenAddressSync(
+        xpMintKeypair.publicKey,
+        receiptLearner.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountInstruction(
+            authority.publicKey,
+            receiptLearnerTokenAccount,
+            receiptLearner.publicKey,
+            xpMintKeypair.publicKey,
+            TOKEN_2022_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+          )
+        )
+      );
+
+      const [receiptEnrollPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("enrollment"),
+          Buffer.from(credCourseId),
+          receiptLearner.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const [receiptPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("completion"),
+          credCoursePda.toBuffer(),
+          receiptLearner.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const [receiptProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("learner"), receiptLearner.publicKey.toBuffer()],
+        program.programId
+      );
+      const [receiptCredentialPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("credential"),
+          trackIdSeed(credTrackId),
+          receiptLearner.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .enroll(credCourseId)
+        .accountsPartial({
+          course: credCoursePda,
+          enrollment: receiptEnrollPda,
+          learner: receiptLearner.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([receiptLearner])
+        .rpc();
+
+      for (let i = 0; i < 2; i++) {
+        await program.methods
+          .completeLesson(i)
+          .accountsPartial({
+            config: configPda,
+            course: credCoursePda,
+            enrollment: receiptEnrollPda,
+            learner: receiptLearner.publicKey,
+            learnerTokenAccount: receiptLearnerTokenAccount,
+            xpMint: xpMintKeypair.publicKey,
+            backendSigner: authority.publicKey,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc();
+      }
+
+      await program.methods
+        .finalizeCourse()
+        .accountsPartial({
+          config: configPda,
+          course: credCoursePda,
+          enrollment: receiptEnrollPda,
+          learner: receiptLearner.publicKey,
+          learnerTokenAccount: receiptLearnerTokenAccount,
+          creatorTokenAccount: creatorTokenAccount,
+          creator: creator.publicKey,
+          xpMint: xpMintKeypair.publicKey,
+          backendSigner: authority.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      // Close before the backend issues the credential
+      await program.methods
+        .closeEnrollment()
+        .accountsPartial({
+          course: credCoursePda,
+          enrollment: receiptEnrollPda,
+          completionReceipt: receiptPda,
+          learner: receiptLearner.publicKey,
+        })
+        .signers([receiptLearner])
+        .rpc();
+
+      const receiptAsset = Keypair.generate();
+      await program.methods
+        .issueCredential()
+        .accountsPartial({
+          config: configPda,
+          course: credCoursePda,
+          completionReceipt: receiptPda,
+          learnerProfile: receiptProfilePda,
+          track: credTrackPda,
+          trackCredential: receiptCredentialPda,
+          learner: receiptLearner.publicKey,
+          credentialAsset: receiptAsset.publicKey,
+          trackCollection: collectionAddress,
+          payer: authority.publicKey,
+          backendSigner: authority.publicKey,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([receiptAsset])
+        .rpc();
+
+      const receipt = await program.account.completionReceipt.fetch(receiptPda);
+      expect(receipt.credentialAsset.toBase58()).to.equal(
+        receiptAsset.publicKey.toBase58()
+      );
+      const trackCredential = await program.account.trackCredential.fetch(
+        receiptCredentialPda
+      );
+      expect(trackCredential.asset.toBase58()).to.equal(
+        receiptAsset.publicKey.toBase58()
+      );
+      expect(trackCredential.coursesCredited).to.equal(1);
+
+      // The receipt now records the asset, so it cannot be credited twice
+      const anotherAsset = Keypair.generate();
+      try {
+        await program.methods
+          .issueCredential()
+          .accountsPartial({
+            config: configPda,
+            course: credCoursePda,
+            completionReceipt: receiptPda,
+            learnerProfile: receiptProfilePda,
+            track: credTrackPda,
+            trackCredential: receiptCredentialPda,
+            learner: receiptLearner.publicKey,
+            credentialAsset: anotherAsset.publicKey,
+            trackCollection: collectionAddress,
+            payer: authority.publicKey,
+            backendSigner: authority.publicKey,
+            mplCoreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([anotherAsset])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err) {
+        if (err instanceof AnchorError) {
+          expect(err.error.errorCode.code).to.equal("CredentialAlreadyIssued");
+        } else {
+          expect(err.toString()).to.contain("CredentialAlreadyIssued");
+        }
+      }
+    });
+
     it("upgrades existing credential", async () => {
+      // Point the track at new metadata; upgrade re-derives the URI
+      await program.methods
+        .updateTrack({
+          newName: null,
+          newUriTemplate: "https://arweave.net/track-1-v2/{level}/{learner}",
+          newMaxLevel: null,
+          newCollection: false,
+          newValidityPeriod: null,
+          newRecertificationCourse: null,
+        })
+        .accountsPartial({
+          config: configPda,
+          track: credTrackPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
       const sig = await program.methods
-        .upgradeCredential("Solana Track - Level 1 (Updated)", "https://arweave.net/cred-metadata-v2", 2, new anchor.BN(1000))
+        .upgradeCredential()
         .accountsPartial({
           config: configPda,
           course: credCoursePda,
           enrollment: credEnrollPda,
+          learnerProfile: credLearnerProfilePda,
+          track: credTrackPda,
+          trackCredential: credTrackCredentialPda,
           learner: credLearner.publicKey,
           credentialAsset: credentialKeypair.publicKey,
           trackCollection: collectionAddress,
@@ -2316,8 +2884,10 @@ describe("onchain-academy", () => {
         fromWeb3JsPublicKey(credentialKeypair.publicKey)
       );
 
-      expect(asset.name).to.equal("Solana Track - Level 1 (Updated)");
-      expect(asset.uri).to.equal("https://arweave.net/cred-metadata-v2");
+      expect(asset.name).to.equal("Solana Track - Level 1");
+      expect(asset.uri).to.equal(
+        `https://arweave.net/track-1-v2/1/${credLearner.publicKey.toBase58()}`
+      );
 
       // Enrollment credential_asset should remain unchanged
       const enrollment = await program.account.enrollment.fetch(credEnrollPda);
@@ -2331,11 +2901,14 @@ describe("onchain-academy", () => {
 
       try {
         await program.methods
-          .upgradeCredential("Wrong Asset", "https://arweave.net/wrong", 2, new anchor.BN(1000))
+          .upgradeCredential()
           .accountsPartial({
             config: configPda,
             course: credCoursePda,
             enrollment: credEnrollPda,
+            learnerProfile: credLearnerProfilePda,
+            track: credTrackPda,
+            trackCredential: credTrackCredentialPda,
             learner: credLearner.publicKey,
             credentialAsset: wrongAssetKeypair.publicKey,
             trackCollection: collectionAddress,
@@ -2392,9 +2965,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 10,
           trackId: 20,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 0,
           minCompletionsForReward: 0,
+          completionBonusXp: 10,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: secCoursePda,
@@ -2523,7 +3105,7 @@ describe("onchain-academy", () => {
   // 14. Edge cases
   // ===========================================================================
   describe("14. Edge cases", () => {
-    it("finalize with computed bonus and zero creator reward", async () => {
+    it("finalize with completion bonus and zero creator reward", async () => {
       const zeroCourseId = "zero-bonus-course";
       const [zeroCoursePda] = PublicKey.findProgramAddressSync(
         [Buffer.from("course"), Buffer.from(zeroCourseId)],
@@ -2537,7 +3119,7 @@ describe("onchain-academy", () => {
       );
       await provider.connection.confirmTransaction(airdropSig, "confirmed");
 
-      // Create course with zero creator reward (bonus is now computed automatically)
+      // Create course with zero creator reward
       await program.methods
         .createCourse({
           courseId: zeroCourseId,
@@ -2548,9 +3130,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 75,
           trackId: 30,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 0,
           minCompletionsForReward: 0,
+          completionBonusXp: 75,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: zeroCoursePda,
@@ -2638,7 +3229,7 @@ describe("onchain-academy", () => {
       const enrollment = await program.account.enrollment.fetch(zeroEnrollPda);
       expect(enrollment.completedAt).to.not.be.null;
 
-      // Learner XP: 75 * 2 (lessons) + floor(75*2/2) (bonus) = 150 + 75 = 225
+      // Learner XP: 75 * 2 (lessons) + 75 (bonus) = 150 + 75 = 225
       const learnerAta = await getAccount(
         provider.connection,
         zeroLearnerTokenAccount,
@@ -2672,9 +3263,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 200,
           trackId: 31,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 10,
           minCompletionsForReward: 1,
+          completionBonusXp: 100,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: singleCoursePda,
@@ -2766,7 +3366,7 @@ describe("onchain-academy", () => {
       const course = await program.account.course.fetch(singleCoursePda);
       expect(course.totalCompletions).to.equal(1);
 
-      // Learner XP: 200 (lesson) + 100 (bonus = floor(200*1/2)) = 300
+      // Learner XP: 200 (lesson) + 100 (bonus) = 300
       const learnerAta = await getAccount(
         provider.connection,
         singleLearnerTokenAccount,
@@ -2834,6 +3434,9 @@ describe("onchain-academy", () => {
           minter: testMinter.publicKey,
           label: "test-minter",
           maxXpPerCall: new BN(1000),
+          windowSeconds: new BN(0),
+          maxXpPerWindow: new BN(0),
+          maxTotalXp: new BN(0),
         })
         .accountsPartial({
           config: configPda,
@@ -2861,8 +3464,10 @@ describe("onchain-academy", () => {
           config: configPda,
           minterRole: testMinterRolePda,
           xpMint: xpMintKeypair.publicKey,
+          recipient: minterRecipient.publicKey,
           recipientTokenAccount: minterRecipientTokenAccount,
           minter: testMinter.publicKey,
+          payer: authority.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .signers([testMinter])
@@ -2889,8 +3494,10 @@ describe("onchain-academy", () => {
             config: configPda,
             minterRole: testMinterRolePda,
             xpMint: xpMintKeypair.publicKey,
+            recipient: minterRecipient.publicKey,
             recipientTokenAccount: minterRecipientTokenAccount,
             minter: testMinter.publicKey,
+            payer: authority.publicKey,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
           })
           .signers([testMinter])
@@ -2912,8 +3519,10 @@ describe("onchain-academy", () => {
             config: configPda,
             minterRole: testMinterRolePda,
             xpMint: xpMintKeypair.publicKey,
+            recipient: minterRecipient.publicKey,
             recipientTokenAccount: minterRecipientTokenAccount,
             minter: testMinter.publicKey,
+            payer: authority.publicKey,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
           })
           .signers([testMinter])
@@ -2958,8 +3567,10 @@ describe("onchain-academy", () => {
             config: configPda,
             minterRole: testMinterRolePda,
             xpMint: xpMintKeypair.publicKey,
+            recipient: minterRecipient.publicKey,
             recipientTokenAccount: minterRecipientTokenAccount,
             minter: testMinter.publicKey,
+            payer: authority.publicKey,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
           })
           .signers([testMinter])
@@ -3505,9 +4116,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 10,
           trackId: 40,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 0,
           minCompletionsForReward: 0,
+          completionBonusXp: 325,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: bitmapCoursePda,
@@ -3641,9 +4261,18 @@ describe("onchain-academy", () => {
           xpPerLesson: 10,
           trackId: 41,
           trackLevel: 1,
-          prerequisite: null,
+          prerequisites: [],
+          prerequisiteMinCount: 0,
           creatorRewardXp: 0,
           minCompletionsForReward: 0,
+          completionBonusXp: 5,
+          completionBonusBps: 0,
+          enrollOpensAt: new BN(0),
+          enrollClosesAt: new BN(0),
+          completionDeadline: new BN(0),
+          maxEnrollments: 0,
+          cohortOnly: false,
+          progressExpiresAfter: new BN(0),
         })
         .accountsPartial({
           course: reEnrollCoursePda,
@@ -3724,11 +4353,20 @@ describe("onchain-academy", () => {
         .rpc();
 
       // Close completed enrollment
+      const [receiptPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("completion"),
+          reEnrollCoursePda.toBuffer(),
+          reEnrollLearner.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
       await program.methods
         .closeEnrollment()
         .accountsPartial({
           course: reEnrollCoursePda,
           enrollment: reEnrollEnrollPda,
+          completionReceipt: receiptPda,
           learner: reEnrollLearner.publicKey,
         })
         .signers([reEnrollLearner])
@@ -3792,6 +4430,8 @@ describe("onchain-academy", () => {
       await program.methods
         .updateConfig({
           newBackendSigner: newSigner.publicKey,
+          newDailyXpCap: null,
+          newGuardian: null,
         })
         .accountsPartial({
           config: configPda,
@@ -3820,6 +4460,8 @@ describe("onchain-academy", () => {
       await program.methods
         .updateConfig({
           newBackendSigner: authority.publicKey,
+          newDailyXpCap: null,
+          newGuardian: null,
         })
         .accountsPartial({
           config: configPda,
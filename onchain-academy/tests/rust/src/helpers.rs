@@ -11,6 +11,14 @@ pub fn course_pda(course_id: &str) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"course", course_id.as_bytes()], &PROGRAM_ID)
 }
 
+pub fn course_tombstone_pda(course_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"course_tombstone", course_id.as_bytes()], &PROGRAM_ID)
+}
+
+pub fn lesson_xp_pda(course: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"lesson_xp", course.as_ref()], &PROGRAM_ID)
+}
+
 pub fn enrollment_pda(course_id: &str, learner: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[b"enrollment", course_id.as_bytes(), learner.as_ref()],
@@ -18,10 +26,54 @@ pub fn enrollment_pda(course_id: &str, learner: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
+pub fn cohort_pda(course: &Pubkey, cohort_id: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"cohort", course.as_ref(), &cohort_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn completion_receipt_pda(course: &Pubkey, learner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"completion", course.as_ref(), learner.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn lesson_map_pda(course: &Pubkey, to_version: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"lesson_map", course.as_ref(), &to_version.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn course_author_pda(author: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"course_author", author.as_ref()], &PROGRAM_ID)
+}
+
+pub fn track_pda(track_id: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"track", &track_id.to_le_bytes()], &PROGRAM_ID)
+}
+
+pub fn track_credential_pda(track_id: u16, learner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"credential", &track_id.to_le_bytes(), learner.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn learner_profile_pda(learner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"learner", learner.as_ref()], &PROGRAM_ID)
+}
+
 pub fn minter_role_pda(minter: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"minter", minter.as_ref()], &PROGRAM_ID)
 }
 
+pub fn backend_signer_pda(signer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"backend_signer", signer.as_ref()], &PROGRAM_ID)
+}
+
 pub fn achievement_type_pda(achievement_id: &str) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"achievement", achievement_id.as_bytes()], &PROGRAM_ID)
 }
@@ -9,8 +9,24 @@ mod test_enrollment;
 #[cfg(test)]
 mod test_initialize;
 #[cfg(test)]
+mod test_learner_profile;
+#[cfg(test)]
 mod test_utils;
 #[cfg(test)]
 mod test_minter_role;
 #[cfg(test)]
 mod test_achievement;
+#[cfg(test)]
+mod test_backend_signer;
+#[cfg(test)]
+mod test_attestation;
+#[cfg(test)]
+mod test_cohort;
+#[cfg(test)]
+mod test_lesson_map;
+#[cfg(test)]
+mod test_course_author;
+#[cfg(test)]
+mod test_track;
+#[cfg(test)]
+mod test_track_credential;
@@ -2,7 +2,7 @@ use crate::helpers::*;
 use anchor_lang::{AnchorDeserialize, AnchorSerialize};
 use solana_sdk::pubkey::Pubkey;
 use onchain_academy::state::{
-    AchievementReceipt, AchievementType, MAX_ACHIEVEMENT_ID_LEN, MAX_ACHIEVEMENT_NAME_LEN,
+    AchievementReceipt, AchievementType, AchievementTypeV0, MAX_ACHIEVEMENT_ID_LEN, MAX_ACHIEVEMENT_NAME_LEN,
     MAX_ACHIEVEMENT_URI_LEN,
 };
 
@@ -10,11 +10,12 @@ use onchain_academy::state::{
 
 #[test]
 fn achievement_type_size_constant_is_correct() {
-    // 8 (discriminator) + (4 + 32) (achievement_id) + (4 + 64) (name)
+    // 8 (discriminator) + 1 (schema_version) + (4 + 32) (achievement_id) + (4 + 64) (name)
     // + (4 + 128) (metadata_uri) + 32 (collection) + 32 (creator)
     // + 4 (max_supply) + 4 (current_supply) + 4 (xp_reward)
-    // + 1 (is_active) + 8 (created_at) + 8 (_reserved) + 1 (bump)
-    assert_eq!(AchievementType::SIZE, 338);
+    // + 1 (is_active) + 8 (created_at) + 4 (revoked_count) + 28 (_reserved) + 1 (bump)
+    assert_eq!(AchievementType::SIZE, 363);
+    assert_eq!(AchievementType::LEGACY_SIZE, 338);
 }
 
 #[test]
@@ -35,6 +36,7 @@ fn max_achievement_uri_len_is_128() {
 #[test]
 fn achievement_type_serialization_roundtrip() {
     let achievement = AchievementType {
+        schema_version: 1,
         achievement_id: "early-adopter".to_string(),
         name: "Early Adopter Badge".to_string(),
         metadata_uri: "https://arweave.net/abc123".to_string(),
@@ -45,7 +47,8 @@ fn achievement_type_serialization_roundtrip() {
         xp_reward: 500,
         is_active: true,
         created_at: 1700000000,
-        _reserved: [0u8; 8],
+        revoked_count: 0,
+        _reserved: [0u8; 28],
         bump: 252,
     };
 
@@ -64,13 +67,14 @@ fn achievement_type_serialization_roundtrip() {
     assert_eq!(deserialized.xp_reward, 500);
     assert!(deserialized.is_active);
     assert_eq!(deserialized.created_at, 1700000000);
-    assert_eq!(deserialized._reserved, [0u8; 8]);
+    assert_eq!(deserialized._reserved, [0u8; 28]);
     assert_eq!(deserialized.bump, 252);
 }
 
 #[test]
 fn achievement_type_serialized_size_matches_constant() {
     let achievement = AchievementType {
+        schema_version: 1,
         achievement_id: "a".repeat(MAX_ACHIEVEMENT_ID_LEN),
         name: "a".repeat(MAX_ACHIEVEMENT_NAME_LEN),
         metadata_uri: "a".repeat(MAX_ACHIEVEMENT_URI_LEN),
@@ -81,7 +85,8 @@ fn achievement_type_serialized_size_matches_constant() {
         xp_reward: 0,
         is_active: true,
         created_at: 0,
-        _reserved: [0u8; 8],
+        revoked_count: 0,
+        _reserved: [0u8; 28],
         bump: 0,
     };
 
@@ -95,6 +100,7 @@ fn achievement_type_serialized_size_matches_constant() {
 #[test]
 fn achievement_type_shorter_strings_fit_within_allocation() {
     let achievement = AchievementType {
+        schema_version: 1,
         achievement_id: "short".to_string(),
         name: "Short".to_string(),
         metadata_uri: "https://x.co".to_string(),
@@ -105,7 +111,8 @@ fn achievement_type_shorter_strings_fit_within_allocation() {
         xp_reward: 0,
         is_active: true,
         created_at: 0,
-        _reserved: [0u8; 8],
+        revoked_count: 0,
+        _reserved: [0u8; 28],
         bump: 0,
     };
 
@@ -118,6 +125,7 @@ fn achievement_type_shorter_strings_fit_within_allocation() {
 #[test]
 fn achievement_type_unlimited_supply() {
     let achievement = AchievementType {
+        schema_version: 1,
         achievement_id: "unlimited".to_string(),
         name: "Unlimited Badge".to_string(),
         metadata_uri: "https://arweave.net/xyz".to_string(),
@@ -128,7 +136,8 @@ fn achievement_type_unlimited_supply() {
         xp_reward: 100,
         is_active: true,
         created_at: 0,
-        _reserved: [0u8; 8],
+        revoked_count: 0,
+        _reserved: [0u8; 28],
         bump: 1,
     };
 
@@ -136,9 +145,42 @@ fn achievement_type_unlimited_supply() {
     assert_eq!(achievement.max_supply, 0);
 }
 
+#[test]
+fn revoked_awards_free_supply_without_lowering_current_supply() {
+    let mut achievement = AchievementType {
+        schema_version: 1,
+        achievement_id: "limited".to_string(),
+        name: "Limited Badge".to_string(),
+        metadata_uri: "https://arweave.net/xyz".to_string(),
+        collection: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        max_supply: 2,
+        current_supply: 2,
+        xp_reward: 0,
+        is_active: true,
+        created_at: 0,
+        revoked_count: 0,
+        _reserved: [0u8; 28],
+        bump: 1,
+    };
+    assert!(!achievement.has_supply());
+
+    // revoke_achievement only bumps revoked_count, so the next award is
+    // numbered 3 rather than reusing 2
+    achievement.revoked_count = 1;
+    assert!(achievement.has_supply());
+
+    achievement.current_supply = 3;
+    assert!(!achievement.has_supply());
+
+    achievement.max_supply = 0;
+    assert!(achievement.has_supply());
+}
+
 #[test]
 fn achievement_type_reserved_bytes_are_zeroed() {
     let achievement = AchievementType {
+        schema_version: 1,
         achievement_id: "test".to_string(),
         name: "Test".to_string(),
         metadata_uri: "https://test.com".to_string(),
@@ -149,12 +191,13 @@ fn achievement_type_reserved_bytes_are_zeroed() {
         xp_reward: 0,
         is_active: true,
         created_at: 0,
-        _reserved: [0u8; 8],
+        revoked_count: 0,
+        _reserved: [0u8; 28],
         bump: 1,
     };
 
-    assert_eq!(achievement._reserved, [0u8; 8]);
-    assert_eq!(achievement._reserved.len(), 8);
+    assert_eq!(achievement._reserved, [0u8; 28]);
+    assert_eq!(achievement._reserved.len(), 28);
 }
 
 #[test]
@@ -185,6 +228,32 @@ fn different_achievement_ids_yield_different_pdas() {
     assert_ne!(pda_a, pda_b);
 }
 
+#[test]
+fn legacy_achievement_type_layout_matches_legacy_size() {
+    let legacy = AchievementTypeV0 {
+        achievement_id: "a".repeat(MAX_ACHIEVEMENT_ID_LEN),
+        name: "a".repeat(MAX_ACHIEVEMENT_NAME_LEN),
+        metadata_uri: "a".repeat(MAX_ACHIEVEMENT_URI_LEN),
+        collection: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        max_supply: 10,
+        current_supply: 3,
+        xp_reward: 100,
+        is_active: true,
+        created_at: 1700000000,
+        _reserved: [0u8; 8],
+        bump: 248,
+    };
+
+    let mut buf = Vec::new();
+    legacy.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, AchievementType::LEGACY_SIZE);
+
+    let decoded = AchievementTypeV0::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(decoded.current_supply, 3);
+    assert_eq!(decoded.bump, 248);
+}
+
 // --- AchievementReceipt ---
 
 #[test]
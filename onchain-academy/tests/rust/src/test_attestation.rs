@@ -0,0 +1,98 @@
+use solana_sdk::pubkey::Pubkey;
+use onchain_academy::instructions::{lesson_attestation_message, LESSON_ATTESTATION_LEN};
+use onchain_academy::utils::parse_ed25519_instruction;
+
+/// Tests for `complete_lesson_attested`: message layout, Ed25519 precompile
+/// instruction parsing.
+
+/// Builds Ed25519 precompile data in the layout produced by
+/// `new_ed25519_instruction`: header, public key, signature, message.
+fn ed25519_data(public_key: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+    let public_key_offset: u16 = 16;
+    let signature_offset: u16 = public_key_offset + 32;
+    let message_offset: u16 = signature_offset + 64;
+
+    let mut data = vec![1u8, 0u8];
+    for value in [
+        signature_offset,
+        instruction_index,
+        public_key_offset,
+        instruction_index,
+        message_offset,
+        message.len() as u16,
+        instruction_index,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(public_key.as_ref());
+    data.extend_from_slice(&[0u8; 64]);
+    data.extend_from_slice(message);
+    data
+}
+
+#[test]
+fn attestation_message_layout() {
+    let course = Pubkey::new_unique();
+    let learner = Pubkey::new_unique();
+    let message = lesson_attestation_message(&course, &learner, 7, 1700000000, 3, 2, 1700000600);
+
+    assert_eq!(message.len(), LESSON_ATTESTATION_LEN);
+    assert_eq!(LESSON_ATTESTATION_LEN, 85);
+    assert_eq!(&message[..32], course.as_ref());
+    assert_eq!(&message[32..64], learner.as_ref());
+    assert_eq!(message[64], 7);
+    assert_eq!(&message[65..73], &1700000000i64.to_le_bytes());
+    assert_eq!(&message[73..75], &3u16.to_le_bytes());
+    assert_eq!(&message[75..77], &2u16.to_le_bytes());
+    assert_eq!(&message[77..], &1700000600i64.to_le_bytes());
+}
+
+#[test]
+fn attestation_message_binds_every_field() {
+    let course = Pubkey::new_unique();
+    let learner = Pubkey::new_unique();
+    let base = lesson_attestation_message(&course, &learner, 1, 50, 0, 0, 100);
+
+    assert_ne!(base, lesson_attestation_message(&Pubkey::new_unique(), &learner, 1, 50, 0, 0, 100));
+    assert_ne!(base, lesson_attestation_message(&course, &Pubkey::new_unique(), 1, 50, 0, 0, 100));
+    assert_ne!(base, lesson_attestation_message(&course, &learner, 2, 50, 0, 0, 100));
+    assert_ne!(base, lesson_attestation_message(&course, &learner, 1, 51, 0, 0, 100));
+    assert_ne!(base, lesson_attestation_message(&course, &learner, 1, 50, 1, 0, 100));
+    assert_ne!(base, lesson_attestation_message(&course, &learner, 1, 50, 0, 1, 100));
+    assert_ne!(base, lesson_attestation_message(&course, &learner, 1, 50, 0, 0, 101));
+}
+
+#[test]
+fn parses_single_signature_instruction() {
+    let signer = Pubkey::new_unique();
+    let message = lesson_attestation_message(&Pubkey::new_unique(), &signer, 3, 50, 0, 0, 100);
+    let data = ed25519_data(&signer, &message, u16::MAX);
+
+    let (parsed_signer, parsed_message) = parse_ed25519_instruction(&data).unwrap();
+    assert_eq!(parsed_signer, signer);
+    assert_eq!(parsed_message, &message[..]);
+}
+
+#[test]
+fn rejects_data_from_other_instructions() {
+    // Offsets pointing into another instruction would let an attacker verify
+    // one message and present a different one to the program
+    let data = ed25519_data(&Pubkey::new_unique(), b"payload", 0);
+    assert!(parse_ed25519_instruction(&data).is_err());
+}
+
+#[test]
+fn rejects_multiple_or_zero_signatures() {
+    let mut data = ed25519_data(&Pubkey::new_unique(), b"payload", u16::MAX);
+    data[0] = 2;
+    assert!(parse_ed25519_instruction(&data).is_err());
+    data[0] = 0;
+    assert!(parse_ed25519_instruction(&data).is_err());
+}
+
+#[test]
+fn rejects_truncated_data() {
+    let data = ed25519_data(&Pubkey::new_unique(), b"payload", u16::MAX);
+    assert!(parse_ed25519_instruction(&data[..10]).is_err());
+    assert!(parse_ed25519_instruction(&data[..data.len() - 1]).is_err());
+}
@@ -0,0 +1,77 @@
+use crate::helpers::*;
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use solana_sdk::pubkey::Pubkey;
+use onchain_academy::state::{BackendSigner, MAX_LABEL_LEN};
+
+fn entry(scopes: u8, expires_at: i64) -> BackendSigner {
+    BackendSigner {
+        schema_version: 1,
+        signer: Pubkey::new_unique(),
+        label: "eu-west".to_string(),
+        scopes,
+        expires_at,
+        created_at: 1700000000,
+        _reserved: [0u8; 32],
+        bump: 254,
+    }
+}
+
+#[test]
+fn backend_signer_size_constant_is_correct() {
+    // 8 (discriminator) + 1 (schema_version) + 32 (signer) + (4 + 32) (label)
+    // + 1 (scopes) + 8 (expires_at) + 8 (created_at) + 32 (_reserved) + 1 (bump)
+    assert_eq!(BackendSigner::SIZE, 127);
+}
+
+#[test]
+fn backend_signer_serialized_size_matches_constant() {
+    let mut signer = entry(BackendSigner::ALL_SCOPES, 0);
+    signer.label = "a".repeat(MAX_LABEL_LEN);
+
+    let mut buf = Vec::new();
+    signer.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, BackendSigner::SIZE);
+
+    let deserialized = BackendSigner::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(deserialized.signer, signer.signer);
+    assert_eq!(deserialized.scopes, BackendSigner::ALL_SCOPES);
+    assert_eq!(deserialized.bump, 254);
+}
+
+#[test]
+fn scopes_are_distinct_bits() {
+    assert_eq!(BackendSigner::SCOPE_LESSONS, 0b001);
+    assert_eq!(BackendSigner::SCOPE_FINALIZE, 0b010);
+    assert_eq!(BackendSigner::SCOPE_CREDENTIALS, 0b100);
+    assert_eq!(BackendSigner::ALL_SCOPES, 0b111);
+}
+
+#[test]
+fn allows_only_granted_scopes() {
+    let signer = entry(BackendSigner::SCOPE_LESSONS | BackendSigner::SCOPE_FINALIZE, 0);
+    assert!(signer.allows(BackendSigner::SCOPE_LESSONS, 1700000000));
+    assert!(signer.allows(BackendSigner::SCOPE_FINALIZE, 1700000000));
+    assert!(!signer.allows(BackendSigner::SCOPE_CREDENTIALS, 1700000000));
+}
+
+#[test]
+fn zero_expiry_never_expires() {
+    let signer = entry(BackendSigner::SCOPE_LESSONS, 0);
+    assert!(signer.allows(BackendSigner::SCOPE_LESSONS, i64::MAX));
+}
+
+#[test]
+fn expiry_is_exclusive() {
+    let signer = entry(BackendSigner::SCOPE_LESSONS, 1700086400);
+    assert!(signer.allows(BackendSigner::SCOPE_LESSONS, 1700086399));
+    assert!(!signer.allows(BackendSigner::SCOPE_LESSONS, 1700086400));
+    assert!(!signer.allows(BackendSigner::SCOPE_LESSONS, 1700090000));
+}
+
+#[test]
+fn backend_signer_pda_differs_from_minter_pda() {
+    let key = Pubkey::new_unique();
+    let (signer_pda, _) = backend_signer_pda(&key);
+    let (minter_pda, _) = minter_role_pda(&key);
+    assert_ne!(signer_pda, minter_pda);
+}
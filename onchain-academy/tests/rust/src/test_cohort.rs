@@ -0,0 +1,85 @@
+use crate::helpers::*;
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use solana_sdk::pubkey::Pubkey;
+use onchain_academy::state::Cohort;
+
+fn cohort(opens_at: i64, closes_at: i64, max_enrollments: u32) -> Cohort {
+    Cohort {
+        schema_version: 1,
+        course: Pubkey::new_unique(),
+        cohort_id: 3,
+        enroll_opens_at: opens_at,
+        enroll_closes_at: closes_at,
+        completion_deadline: 0,
+        max_enrollments,
+        total_enrollments: 0,
+        total_completions: 0,
+        is_active: true,
+        created_at: 1700000000,
+        _reserved: [0u8; 32],
+        bump: 254,
+    }
+}
+
+#[test]
+fn cohort_size_constant_is_correct() {
+    // 8 (discriminator) + 1 (schema_version) + 32 (course) + 2 (cohort_id)
+    // + 8 (enroll_opens_at) + 8 (enroll_closes_at) + 8 (completion_deadline)
+    // + 4 (max_enrollments) + 4 (total_enrollments) + 4 (total_completions)
+    // + 1 (is_active) + 8 (created_at) + 32 (_reserved) + 1 (bump)
+    assert_eq!(Cohort::SIZE, 121);
+
+    let mut buf = Vec::new();
+    cohort(1, 2, 3).serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, Cohort::SIZE);
+
+    let decoded = Cohort::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(decoded.cohort_id, 3);
+    assert_eq!(decoded.max_enrollments, 3);
+    assert_eq!(decoded.bump, 254);
+}
+
+#[test]
+fn cohort_pda_is_per_course_and_id() {
+    let (course_a, _) = course_pda("course-a");
+    let (course_b, _) = course_pda("course-b");
+
+    let (pda, bump) = cohort_pda(&course_a, 1);
+    assert_ne!(pda, cohort_pda(&course_a, 2).0);
+    assert_ne!(pda, cohort_pda(&course_b, 1).0);
+
+    let derived = Pubkey::create_program_address(
+        &[b"cohort", course_a.as_ref(), &1u16.to_le_bytes(), &[bump]],
+        &PROGRAM_ID,
+    );
+    assert_eq!(derived.unwrap(), pda);
+}
+
+#[test]
+fn cohort_window_and_seats() {
+    let mut cohort = cohort(100, 200, 2);
+    assert!(!cohort.enrollment_open(50));
+    assert!(cohort.enrollment_open(150));
+    assert!(!cohort.enrollment_open(200));
+
+    assert!(cohort.has_capacity());
+    cohort.total_enrollments = 2;
+    assert!(!cohort.has_capacity());
+}
+
+#[test]
+fn inactive_cohort_is_closed() {
+    let mut cohort = cohort(0, 0, 0);
+    assert!(cohort.enrollment_open(150));
+    cohort.is_active = false;
+    assert!(!cohort.enrollment_open(150));
+}
+
+#[test]
+fn cohort_deadline() {
+    let mut cohort = cohort(0, 0, 0);
+    assert!(cohort.before_deadline(i64::MAX));
+    cohort.completion_deadline = 500;
+    assert!(cohort.before_deadline(500));
+    assert!(!cohort.before_deadline(501));
+}
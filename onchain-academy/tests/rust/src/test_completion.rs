@@ -139,11 +139,82 @@ fn lesson_out_of_bounds_check() {
     assert!(!(lesson_count + 1 < lesson_count));
 }
 
+/// Mirrors the loop in complete_lessons: every index must be in range and not
+/// yet set (including earlier in the same batch); the bitmap is only committed
+/// if the whole batch passes. Returns the summed XP.
+fn apply_batch(
+    flags: &mut [u64; 4],
+    lesson_indices: &[u8],
+    lesson_count: u8,
+    xp_per_lesson: u32,
+) -> Option<u64> {
+    if lesson_indices.is_empty() {
+        return None;
+    }
+    let mut next = *flags;
+    for &index in lesson_indices {
+        if index >= lesson_count {
+            return None;
+        }
+        let word = (index / 64) as usize;
+        let mask = 1u64 << (index % 64);
+        if next[word] & mask != 0 {
+            return None;
+        }
+        next[word] |= mask;
+    }
+    *flags = next;
+    (xp_per_lesson as u64).checked_mul(lesson_indices.len() as u64)
+}
+
 #[test]
-fn completion_bonus_xp_is_50_percent_of_total() {
-    // finalize_course: bonus = (xp_per_lesson * lesson_count) / 2
+fn batch_completion_sums_xp_and_sets_bits() {
+    let mut flags = [0u64; 4];
+    let xp = apply_batch(&mut flags, &[0, 3, 64, 200], 255, 25);
+    assert_eq!(xp, Some(100));
+    assert_eq!(flags[0], 0b1001);
+    assert_eq!(flags[1], 1);
+    assert_eq!(flags[3], 1u64 << (200 - 192));
+}
+
+#[test]
+fn batch_completion_rejects_duplicates_within_batch() {
+    let mut flags = [0u64; 4];
+    assert_eq!(apply_batch(&mut flags, &[2, 5, 2], 10, 100), None);
+    assert_eq!(flags, [0u64; 4]);
+}
+
+#[test]
+fn batch_completion_rejects_already_completed_lesson() {
+    let mut flags = [0u64; 4];
+    set_lesson(&mut flags, 4);
+    assert_eq!(apply_batch(&mut flags, &[3, 4], 10, 100), None);
+    // Lesson 3 is not set because the whole batch failed
+    assert_eq!(flags[0], 1 << 4);
+}
+
+#[test]
+fn batch_completion_rejects_out_of_bounds_and_empty() {
+    let mut flags = [0u64; 4];
+    assert_eq!(apply_batch(&mut flags, &[0, 10], 10, 100), None);
+    assert_eq!(apply_batch(&mut flags, &[], 10, 100), None);
+    assert_eq!(flags, [0u64; 4]);
+}
+
+#[test]
+fn batch_completion_of_every_lesson_completes_course() {
+    let mut flags = [0u64; 4];
+    let all: Vec<u8> = (0..255).collect();
+    assert_eq!(apply_batch(&mut flags, &all, 255, u32::MAX), Some(255 * u32::MAX as u64));
+    assert!(all_lessons_complete(&flags, 255));
+}
+
+#[test]
+fn completion_bonus_xp_comes_from_course() {
+    // finalize_course: bonus = course.completion_bonus_xp, independent of lesson XP
     let xp_per_lesson: u32 = 100;
     let lesson_count: u8 = 5;
+    let completion_bonus_xp: u32 = 400;
     let creator_reward_xp: u32 = 50;
     let min_completions_for_reward: u16 = 3;
     let total_completions: u32 = 3;
@@ -151,28 +222,17 @@ fn completion_bonus_xp_is_50_percent_of_total() {
     let total_lesson_xp = (xp_per_lesson as u64) * (lesson_count as u64);
     assert_eq!(total_lesson_xp, 500);
 
-    let bonus_xp = total_lesson_xp / 2;
-    assert_eq!(bonus_xp, 250);
+    let bonus_xp = completion_bonus_xp as u64;
 
     // Creator reward is minted if threshold met
     assert!(total_completions >= min_completions_for_reward as u32);
     let total_xp_to_learner = total_lesson_xp + bonus_xp;
-    assert_eq!(total_xp_to_learner, 750);
+    assert_eq!(total_xp_to_learner, 900);
 
     // Creator gets their reward
     assert_eq!(creator_reward_xp, 50);
 }
 
-#[test]
-fn completion_bonus_zero_when_xp_per_lesson_is_one() {
-    // With xp_per_lesson=1, lesson_count=1: bonus = (1*1)/2 = 0 (integer division)
-    let xp_per_lesson: u32 = 1;
-    let lesson_count: u8 = 1;
-    let total_lesson_xp = (xp_per_lesson as u64) * (lesson_count as u64);
-    let bonus_xp = total_lesson_xp / 2;
-    assert_eq!(bonus_xp, 0);
-}
-
 #[test]
 fn creator_reward_below_threshold_means_no_mint() {
     let min_completions_for_reward: u16 = 5;
@@ -1,17 +1,26 @@
 use crate::helpers::*;
 use anchor_lang::{AnchorDeserialize, AnchorSerialize};
 use solana_sdk::pubkey::Pubkey;
-use onchain_academy::state::{Course, MAX_COURSE_ID_LEN};
+use onchain_academy::state::{
+    before_deadline, has_capacity, validate_schedule, window_contains, Course, CourseLessonXp,
+    CourseTombstone, CourseV0, MAX_BASIS_POINTS, MAX_COURSE_ID_LEN, MAX_PREREQUISITES,
+};
 
 #[test]
 fn course_size_constant_is_correct() {
-    // 8 (discriminator) + (4 + 32) (course_id String) + 32 (creator)
+    // 8 (discriminator) + 1 (schema_version) + (4 + 32) (course_id String) + 32 (creator)
     // + 32 (content_tx_id) + 2 (version) + 1 (lesson_count) + 1 (difficulty)
     // + 4 (xp_per_lesson) + 2 (track_id) + 1 (track_level) + (1 + 32) (prerequisite Option<Pubkey>)
     // + 4 (creator_reward_xp) + 2 (min_completions_for_reward)
     // + 4 (total_completions) + 4 (total_enrollments) + 1 (is_active)
-    // + 8 (created_at) + 8 (updated_at) + 8 (_reserved) + 1 (bump)
-    assert_eq!(Course::SIZE, 192);
+    // + 8 (created_at) + 8 (updated_at) + 4 (completion_bonus_xp)
+    // + 1 (has_lesson_xp) + 32 * 3 (additional_prerequisites)
+    // + 1 (prerequisite_min_count) + 8 (enroll_opens_at) + 8 (enroll_closes_at)
+    // + 8 (completion_deadline) + 4 (max_enrollments) + 1 (cohort_only)
+    // + 8 (progress_expires_after) + 2 (lesson_layout_version) + 4 (open_enrollments)
+    // + 32 (author) + 2 (completion_bonus_bps) + 77 (_reserved) + 1 (bump)
+    assert_eq!(Course::SIZE, 441);
+    assert_eq!(Course::LEGACY_SIZE, 192);
 }
 
 #[test]
@@ -22,6 +31,7 @@ fn max_course_id_len_is_32() {
 #[test]
 fn course_serialization_roundtrip() {
     let course = Course {
+        schema_version: 1,
         course_id: "test-course".to_string(),
         creator: Pubkey::new_unique(),
         content_tx_id: [42u8; 32],
@@ -39,7 +49,21 @@ fn course_serialization_roundtrip() {
         is_active: true,
         created_at: 1700000000,
         updated_at: 1700001000,
-        _reserved: [0u8; 8],
+        completion_bonus_xp: 250,
+        has_lesson_xp: false,
+        additional_prerequisites: [Pubkey::default(); 3],
+        prerequisite_min_count: 0,
+        enroll_opens_at: 0,
+        enroll_closes_at: 0,
+        completion_deadline: 0,
+        max_enrollments: 0,
+        cohort_only: false,
+        progress_expires_after: 0,
+        lesson_layout_version: 0,
+        open_enrollments: 0,
+        author: Pubkey::default(),
+        completion_bonus_bps: 0,
+        _reserved: [0u8; 77],
         bump: 253,
     };
 
@@ -48,6 +72,7 @@ fn course_serialization_roundtrip() {
 
     let deserialized = Course::deserialize(&mut buf.as_slice()).unwrap();
 
+    assert_eq!(deserialized.schema_version, Course::SCHEMA_VERSION);
     assert_eq!(deserialized.course_id, "test-course");
     assert_eq!(deserialized.creator, course.creator);
     assert_eq!(deserialized.content_tx_id, [42u8; 32]);
@@ -65,7 +90,9 @@ fn course_serialization_roundtrip() {
     assert!(deserialized.is_active);
     assert_eq!(deserialized.created_at, 1700000000);
     assert_eq!(deserialized.updated_at, 1700001000);
-    assert_eq!(deserialized._reserved, [0u8; 8]);
+    assert_eq!(deserialized.completion_bonus_xp, 250);
+    assert!(!deserialized.has_lesson_xp);
+    assert_eq!(deserialized._reserved, [0u8; 77]);
     assert_eq!(deserialized.bump, 253);
 }
 
@@ -73,6 +100,7 @@ fn course_serialization_roundtrip() {
 fn course_with_prerequisite_roundtrip() {
     let prereq = Pubkey::new_unique();
     let course = Course {
+        schema_version: 1,
         course_id: "advanced".to_string(),
         creator: Pubkey::new_unique(),
         content_tx_id: [0u8; 32],
@@ -90,7 +118,21 @@ fn course_with_prerequisite_roundtrip() {
         is_active: true,
         created_at: 0,
         updated_at: 0,
-        _reserved: [0u8; 8],
+        completion_bonus_xp: 0,
+        has_lesson_xp: false,
+        additional_prerequisites: [Pubkey::default(); 3],
+        prerequisite_min_count: 0,
+        enroll_opens_at: 0,
+        enroll_closes_at: 0,
+        completion_deadline: 0,
+        max_enrollments: 0,
+        cohort_only: false,
+        progress_expires_after: 0,
+        lesson_layout_version: 0,
+        open_enrollments: 0,
+        author: Pubkey::default(),
+        completion_bonus_bps: 0,
+        _reserved: [0u8; 77],
         bump: 1,
     };
 
@@ -136,9 +178,33 @@ fn course_max_id_length_pda() {
     assert_eq!(derived.unwrap(), pda);
 }
 
+#[test]
+fn course_tombstone_size_and_pda() {
+    // 8 (discriminator) + 1 (schema_version) + 32 (course) + 8 (closed_at)
+    // + 8 (_reserved) + 1 (bump)
+    assert_eq!(CourseTombstone::SIZE, 58);
+
+    let tombstone = CourseTombstone {
+        schema_version: CourseTombstone::SCHEMA_VERSION,
+        course: course_pda("closed").0,
+        closed_at: 1700000000,
+        _reserved: [0u8; 8],
+        bump: 254,
+    };
+    let mut buf = Vec::new();
+    tombstone.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, CourseTombstone::SIZE);
+
+    // Keyed by course ID, so it outlives the Course PDA at the same ID
+    let (tombstone_pda, _) = course_tombstone_pda("closed");
+    assert_ne!(tombstone_pda, course_pda("closed").0);
+    assert_ne!(tombstone_pda, course_tombstone_pda("other").0);
+}
+
 #[test]
 fn course_serialized_size_with_max_id_and_all_options() {
     let course = Course {
+        schema_version: 1,
         course_id: "a".repeat(MAX_COURSE_ID_LEN),
         creator: Pubkey::new_unique(),
         content_tx_id: [0u8; 32],
@@ -156,7 +222,21 @@ fn course_serialized_size_with_max_id_and_all_options() {
         is_active: true,
         created_at: 0,
         updated_at: 0,
-        _reserved: [0u8; 8],
+        completion_bonus_xp: 0,
+        has_lesson_xp: false,
+        additional_prerequisites: [Pubkey::default(); 3],
+        prerequisite_min_count: 0,
+        enroll_opens_at: 0,
+        enroll_closes_at: 0,
+        completion_deadline: 0,
+        max_enrollments: 0,
+        cohort_only: false,
+        progress_expires_after: 0,
+        lesson_layout_version: 0,
+        open_enrollments: 0,
+        author: Pubkey::default(),
+        completion_bonus_bps: 0,
+        _reserved: [0u8; 77],
         bump: 0,
     };
 
@@ -170,6 +250,7 @@ fn course_serialized_size_with_max_id_and_all_options() {
 #[test]
 fn course_serialized_size_shorter_id_fits_within_allocation() {
     let course = Course {
+        schema_version: 1,
         course_id: "short".to_string(),
         creator: Pubkey::new_unique(),
         content_tx_id: [0u8; 32],
@@ -187,7 +268,21 @@ fn course_serialized_size_shorter_id_fits_within_allocation() {
         is_active: true,
         created_at: 0,
         updated_at: 0,
-        _reserved: [0u8; 8],
+        completion_bonus_xp: 0,
+        has_lesson_xp: false,
+        additional_prerequisites: [Pubkey::default(); 3],
+        prerequisite_min_count: 0,
+        enroll_opens_at: 0,
+        enroll_closes_at: 0,
+        completion_deadline: 0,
+        max_enrollments: 0,
+        cohort_only: false,
+        progress_expires_after: 0,
+        lesson_layout_version: 0,
+        open_enrollments: 0,
+        author: Pubkey::default(),
+        completion_bonus_bps: 0,
+        _reserved: [0u8; 77],
         bump: 0,
     };
 
@@ -197,3 +292,365 @@ fn course_serialized_size_shorter_id_fits_within_allocation() {
     // Shorter course_id means serialized data fits within allocated SIZE
     assert!(buf.len() + 8 <= Course::SIZE);
 }
+
+#[test]
+fn legacy_course_layout_matches_legacy_size() {
+    let legacy = CourseV0 {
+        course_id: "a".repeat(MAX_COURSE_ID_LEN),
+        creator: Pubkey::new_unique(),
+        content_tx_id: [7u8; 32],
+        version: 4,
+        lesson_count: 12,
+        difficulty: 2,
+        xp_per_lesson: 100,
+        track_id: 3,
+        track_level: 1,
+        prerequisite: Some(Pubkey::new_unique()),
+        creator_reward_xp: 50,
+        min_completions_for_reward: 10,
+        total_completions: 9,
+        total_enrollments: 40,
+        is_active: true,
+        created_at: 1700000000,
+        updated_at: 1700000500,
+        _reserved: [0u8; 8],
+        bump: 251,
+    };
+
+    let mut buf = Vec::new();
+    legacy.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, Course::LEGACY_SIZE);
+
+    let decoded = CourseV0::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(decoded.version, 4);
+    assert_eq!(decoded.total_enrollments, 40);
+    assert_eq!(decoded.bump, 251);
+}
+
+fn course_with_lessons(lesson_count: u8, xp_per_lesson: u32, has_lesson_xp: bool) -> Course {
+    Course {
+        schema_version: 1,
+        course_id: "weighted".to_string(),
+        creator: Pubkey::new_unique(),
+        content_tx_id: [0u8; 32],
+        version: 1,
+        lesson_count,
+        difficulty: 2,
+        xp_per_lesson,
+        track_id: 1,
+        track_level: 1,
+        prerequisite: None,
+        creator_reward_xp: 0,
+        min_completions_for_reward: 0,
+        total_completions: 0,
+        total_enrollments: 0,
+        is_active: true,
+        created_at: 0,
+        updated_at: 0,
+        completion_bonus_xp: 0,
+        has_lesson_xp,
+        additional_prerequisites: [Pubkey::default(); 3],
+        prerequisite_min_count: 0,
+        enroll_opens_at: 0,
+        enroll_closes_at: 0,
+        completion_deadline: 0,
+        max_enrollments: 0,
+        cohort_only: false,
+        progress_expires_after: 0,
+        lesson_layout_version: 0,
+        open_enrollments: 0,
+        author: Pubkey::default(),
+        completion_bonus_bps: 0,
+        _reserved: [0u8; 77],
+        bump: 1,
+    }
+}
+
+fn lesson_xp_table(lesson_xp: Vec<u32>) -> CourseLessonXp {
+    CourseLessonXp {
+        schema_version: 1,
+        course: Pubkey::new_unique(),
+        lesson_xp,
+        _reserved: [0u8; 16],
+        bump: 252,
+    }
+}
+
+#[test]
+fn lesson_xp_table_space_matches_serialized_size() {
+    for lesson_count in [1u8, 10, 255] {
+        let table = lesson_xp_table(vec![u32::MAX; lesson_count as usize]);
+        let mut buf = Vec::new();
+        table.serialize(&mut buf).unwrap();
+        assert_eq!(buf.len() + 8, CourseLessonXp::space(lesson_count));
+    }
+    // 8 + 1 + 32 + (4 + 4 * 255) + 16 + 1
+    assert_eq!(CourseLessonXp::space(255), 1082);
+}
+
+#[test]
+fn uniform_course_ignores_table() {
+    let course = course_with_lessons(4, 100, false);
+    let table = lesson_xp_table(vec![1, 2, 3, 4]);
+
+    assert_eq!(course.lesson_xp(2, None).unwrap(), 100);
+    assert_eq!(course.lesson_xp(2, Some(&table)).unwrap(), 100);
+    assert_eq!(course.total_lesson_xp(None).unwrap(), 400);
+}
+
+#[test]
+fn weighted_course_uses_table() {
+    let course = course_with_lessons(4, 100, true);
+    let table = lesson_xp_table(vec![10, 20, 30, 500]);
+
+    assert_eq!(course.lesson_xp(0, Some(&table)).unwrap(), 10);
+    assert_eq!(course.lesson_xp(3, Some(&table)).unwrap(), 500);
+    assert_eq!(course.total_lesson_xp(Some(&table)).unwrap(), 560);
+}
+
+#[test]
+fn weighted_course_requires_table() {
+    // Omitting the optional table must not fall back to xp_per_lesson
+    let course = course_with_lessons(4, 100, true);
+    assert!(course.lesson_xp(0, None).is_err());
+    assert!(course.total_lesson_xp(None).is_err());
+}
+
+#[test]
+fn weighted_total_does_not_overflow_u64() {
+    let course = course_with_lessons(255, 0, true);
+    let table = lesson_xp_table(vec![u32::MAX; 255]);
+    assert_eq!(course.total_lesson_xp(Some(&table)).unwrap(), 255 * u32::MAX as u64);
+}
+
+#[test]
+fn completion_bonus_adds_basis_points_of_weighted_total() {
+    let mut course = course_with_lessons(4, 100, true);
+    let table = lesson_xp_table(vec![10, 20, 30, 540]);
+    let total = course.total_lesson_xp(Some(&table)).unwrap();
+
+    course.completion_bonus_xp = 25;
+    assert_eq!(course.completion_bonus(total).unwrap(), 25);
+
+    course.completion_bonus_bps = 5_000;
+    assert_eq!(course.completion_bonus(total).unwrap(), 325);
+
+    course.completion_bonus_xp = 0;
+    course.completion_bonus_bps = MAX_BASIS_POINTS;
+    assert_eq!(course.completion_bonus(total).unwrap(), 600);
+}
+
+#[test]
+fn completion_bonus_basis_points_round_down() {
+    let mut course = course_with_lessons(3, 1, false);
+    course.completion_bonus_bps = 5_000;
+    assert_eq!(course.completion_bonus(3).unwrap(), 1);
+}
+
+#[test]
+fn legacy_completion_bonus_is_half_of_total_lesson_xp() {
+    // migrate_course backfills the bonus schema 0 courses paid implicitly
+    let mut course = course_with_lessons(5, 100, false);
+    course.completion_bonus_bps = Course::LEGACY_COMPLETION_BONUS_BPS;
+    let total = course.total_lesson_xp(None).unwrap();
+    assert_eq!(course.completion_bonus(total).unwrap(), 250);
+
+    // Follows later xp_per_lesson edits instead of freezing the old amount
+    course.xp_per_lesson = 3;
+    course.lesson_count = 3;
+    let total = course.total_lesson_xp(None).unwrap();
+    assert_eq!(course.completion_bonus(total).unwrap(), 4);
+}
+
+#[test]
+fn completion_bonus_handles_largest_totals() {
+    let mut course = course_with_lessons(255, u32::MAX, false);
+    course.completion_bonus_xp = u32::MAX;
+    course.completion_bonus_bps = MAX_BASIS_POINTS;
+    let total = course.total_lesson_xp(None).unwrap();
+    assert_eq!(course.completion_bonus(total).unwrap(), total + u32::MAX as u64);
+}
+
+#[test]
+fn lesson_xp_pda_is_per_course() {
+    let (course_a, _) = course_pda("course-a");
+    let (course_b, _) = course_pda("course-b");
+    assert_ne!(lesson_xp_pda(&course_a).0, lesson_xp_pda(&course_b).0);
+}
+
+#[test]
+fn single_prerequisite_stays_in_legacy_slot() {
+    let prereq = Pubkey::new_unique();
+    let mut course = course_with_lessons(1, 0, false);
+    course.set_prerequisites(&[prereq], 0).unwrap();
+
+    assert_eq!(course.prerequisite, Some(prereq));
+    assert_eq!(course.additional_prerequisites, [Pubkey::default(); 3]);
+    assert_eq!(course.prerequisites(), vec![prereq]);
+    assert_eq!(course.required_prerequisites(), 1);
+}
+
+#[test]
+fn no_prerequisites_are_always_met() {
+    let mut course = course_with_lessons(1, 0, false);
+    course.set_prerequisites(&[], 0).unwrap();
+    assert_eq!(course.prerequisite, None);
+    assert!(course.prerequisites().is_empty());
+    assert!(course.prerequisites_met(&[]));
+}
+
+#[test]
+fn all_mode_requires_every_prerequisite() {
+    let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut course = course_with_lessons(1, 0, false);
+    course.set_prerequisites(&[a, b], 0).unwrap();
+
+    assert_eq!(course.required_prerequisites(), 2);
+    assert!(!course.prerequisites_met(&[a]));
+    assert!(!course.prerequisites_met(&[a, a]));
+    assert!(course.prerequisites_met(&[b, a]));
+}
+
+#[test]
+fn any_mode_accepts_one_prerequisite() {
+    let keys: Vec<Pubkey> = (0..MAX_PREREQUISITES).map(|_| Pubkey::new_unique()).collect();
+    let mut course = course_with_lessons(1, 0, false);
+    course.set_prerequisites(&keys, 1).unwrap();
+
+    assert_eq!(course.prerequisites(), keys);
+    assert!(!course.prerequisites_met(&[]));
+    assert!(!course.prerequisites_met(&[Pubkey::new_unique()]));
+    assert!(course.prerequisites_met(&[keys[3]]));
+}
+
+#[test]
+fn min_count_mode_requires_k_of_n() {
+    let keys: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let mut course = course_with_lessons(1, 0, false);
+    course.set_prerequisites(&keys, 2).unwrap();
+
+    assert!(!course.prerequisites_met(&[keys[0]]));
+    assert!(course.prerequisites_met(&[keys[0], keys[2]]));
+}
+
+#[test]
+fn invalid_prerequisite_sets_are_rejected() {
+    let a = Pubkey::new_unique();
+    let mut course = course_with_lessons(1, 0, false);
+    let too_many: Vec<Pubkey> = (0..=MAX_PREREQUISITES).map(|_| Pubkey::new_unique()).collect();
+
+    assert!(course.set_prerequisites(&too_many, 0).is_err());
+    assert!(course.set_prerequisites(&[a, a], 0).is_err());
+    assert!(course.set_prerequisites(&[Pubkey::default()], 0).is_err());
+    assert!(course.set_prerequisites(&[a], 2).is_err());
+}
+
+#[test]
+fn replacing_prerequisites_clears_old_slots() {
+    let keys: Vec<Pubkey> = (0..MAX_PREREQUISITES).map(|_| Pubkey::new_unique()).collect();
+    let mut course = course_with_lessons(1, 0, false);
+    course.set_prerequisites(&keys, 1).unwrap();
+    course.set_prerequisites(&keys[..1], 0).unwrap();
+
+    assert_eq!(course.prerequisites(), vec![keys[0]]);
+    assert_eq!(course.prerequisite_min_count, 0);
+}
+
+#[test]
+fn credential_at_or_above_track_level_satisfies_course() {
+    let mut course = course_with_lessons(1, 0, false);
+    course.track_id = 7;
+    course.track_level = 2;
+
+    assert!(course.credential_satisfies(7, 2));
+    assert!(course.credential_satisfies(7, 5));
+    assert!(!course.credential_satisfies(7, 1));
+    assert!(!course.credential_satisfies(8, 5));
+}
+
+#[test]
+fn prerequisite_set_can_mix_courses_and_achievements() {
+    // "Complete the intro course, or hold the bootcamp achievement"
+    let (intro, _) = course_pda("intro");
+    let (bootcamp, _) = achievement_type_pda("bootcamp");
+    let mut course = course_with_lessons(1, 0, false);
+    course.set_prerequisites(&[intro, bootcamp], 1).unwrap();
+
+    assert!(course.prerequisites_met(&[bootcamp]));
+    assert!(course.prerequisites_met(&[intro]));
+}
+
+#[test]
+fn default_schedule_is_unrestricted() {
+    let course = course_with_lessons(1, 0, false);
+    assert!(course.enrollment_open(0));
+    assert!(course.enrollment_open(i64::MAX));
+    assert!(course.has_capacity());
+    assert!(before_deadline(course.completion_deadline, i64::MAX));
+}
+
+#[test]
+fn enrollment_window_is_half_open() {
+    assert!(!window_contains(100, 200, 99));
+    assert!(window_contains(100, 200, 100));
+    assert!(window_contains(100, 200, 199));
+    assert!(!window_contains(100, 200, 200));
+    // Either end may be left open
+    assert!(window_contains(0, 200, 1));
+    assert!(window_contains(100, 0, i64::MAX));
+}
+
+#[test]
+fn course_capacity_counts_total_enrollments() {
+    let mut course = course_with_lessons(1, 0, false);
+    course.max_enrollments = 2;
+    course.total_enrollments = 1;
+    assert!(course.has_capacity());
+    course.total_enrollments = 2;
+    assert!(!course.has_capacity());
+    assert!(has_capacity(u32::MAX, 0));
+}
+
+#[test]
+fn completion_deadline_is_inclusive() {
+    assert!(before_deadline(1000, 1000));
+    assert!(!before_deadline(1000, 1001));
+    assert!(before_deadline(0, i64::MAX));
+}
+
+#[test]
+fn schedule_validation() {
+    assert!(validate_schedule(0, 0, 0).is_ok());
+    assert!(validate_schedule(100, 200, 300).is_ok());
+    assert!(validate_schedule(100, 0, 100).is_ok());
+    assert!(validate_schedule(0, 200, 0).is_ok());
+
+    // Empty window
+    assert!(validate_schedule(200, 200, 0).is_err());
+    assert!(validate_schedule(200, 100, 0).is_err());
+    // Deadline before enrollment closes
+    assert!(validate_schedule(100, 200, 150).is_err());
+    assert!(validate_schedule(100, 0, 50).is_err());
+    // Negative timestamps are not a valid "unset"
+    assert!(validate_schedule(-1, 0, 0).is_err());
+}
+
+#[test]
+fn lesson_count_update_rules() {
+    let mut course = course_with_lessons(4, 100, false);
+    assert!(course.validate_lesson_count_update(4).is_ok());
+    assert!(course.validate_lesson_count_update(0).is_err());
+    // No enrollments yet: any size goes
+    assert!(course.validate_lesson_count_update(2).is_ok());
+    assert!(course.validate_lesson_count_update(6).is_ok());
+
+    // Once learners hold bits, only appending is allowed
+    course.total_enrollments = 1;
+    assert!(course.validate_lesson_count_update(6).is_ok());
+    assert!(course.validate_lesson_count_update(3).is_err());
+
+    // A per-lesson XP table pins the count
+    let weighted = course_with_lessons(4, 100, true);
+    assert!(weighted.validate_lesson_count_update(4).is_ok());
+    assert!(weighted.validate_lesson_count_update(5).is_err());
+}
@@ -0,0 +1,109 @@
+use crate::helpers::*;
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use solana_sdk::pubkey::Pubkey;
+use onchain_academy::instructions::UpdateCourseParams;
+use onchain_academy::state::{CourseAuthor, MAX_AUTHOR_TRACKS, MAX_LABEL_LEN};
+
+fn entry(track_ids: Vec<u16>) -> CourseAuthor {
+    CourseAuthor {
+        schema_version: 1,
+        author: Pubkey::new_unique(),
+        label: "core-team".to_string(),
+        track_ids,
+        created_at: 1700000000,
+        _reserved: [0u8; 32],
+        bump: 253,
+    }
+}
+
+fn no_changes() -> UpdateCourseParams {
+    UpdateCourseParams {
+        new_content_tx_id: None,
+        new_is_active: None,
+        new_xp_per_lesson: None,
+        new_creator_reward_xp: None,
+        new_min_completions_for_reward: None,
+        new_completion_bonus_xp: None,
+        new_completion_bonus_bps: None,
+        new_prerequisites: None,
+        new_prerequisite_min_count: None,
+        new_enroll_opens_at: None,
+        new_enroll_closes_at: None,
+        new_completion_deadline: None,
+        new_max_enrollments: None,
+        new_cohort_only: None,
+        new_progress_expires_after: None,
+        new_lesson_count: None,
+        new_difficulty: None,
+        new_track_id: None,
+        new_track_level: None,
+        new_creator: None,
+        new_author: None,
+    }
+}
+
+#[test]
+fn course_author_size_constant_is_correct() {
+    // 8 (discriminator) + 1 (schema_version) + 32 (author) + (4 + 32) (label)
+    // + (4 + 2 * 8) (track_ids) + 8 (created_at) + 32 (_reserved) + 1 (bump)
+    assert_eq!(CourseAuthor::SIZE, 138);
+}
+
+#[test]
+fn course_author_serialized_size_matches_constant() {
+    let mut author = entry((0..MAX_AUTHOR_TRACKS as u16).collect());
+    author.label = "a".repeat(MAX_LABEL_LEN);
+
+    let mut buf = Vec::new();
+    author.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, CourseAuthor::SIZE);
+
+    let deserialized = CourseAuthor::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(deserialized.author, author.author);
+    assert_eq!(deserialized.track_ids.len(), MAX_AUTHOR_TRACKS);
+    assert_eq!(deserialized.bump, 253);
+}
+
+#[test]
+fn allows_only_assigned_tracks() {
+    let author = entry(vec![0, 7]);
+    assert!(author.allows_track(0));
+    assert!(author.allows_track(7));
+    assert!(!author.allows_track(1));
+}
+
+#[test]
+fn authors_may_edit_content_and_xp_only() {
+    let mut params = no_changes();
+    params.new_content_tx_id = Some([1u8; 32]);
+    params.new_xp_per_lesson = Some(10);
+    params.new_completion_bonus_xp = Some(50);
+    params.new_completion_bonus_bps = Some(1_000);
+    assert!(params.author_editable());
+
+    params.new_is_active = Some(false);
+    assert!(!params.author_editable());
+
+    // Creator rewards stay with the authority
+    let mut params = no_changes();
+    params.new_creator_reward_xp = Some(5);
+    assert!(!params.author_editable());
+
+    let mut params = no_changes();
+    params.new_min_completions_for_reward = Some(0);
+    assert!(!params.author_editable());
+
+    let mut params = no_changes();
+    params.new_creator = Some(Pubkey::new_unique());
+    assert!(!params.author_editable());
+
+    let mut params = no_changes();
+    params.new_author = Some(Pubkey::default());
+    assert!(!params.author_editable());
+}
+
+#[test]
+fn course_author_pda_differs_from_backend_signer_pda() {
+    let key = Pubkey::new_unique();
+    assert_ne!(course_author_pda(&key).0, backend_signer_pda(&key).0);
+}
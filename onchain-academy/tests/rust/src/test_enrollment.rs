@@ -1,27 +1,34 @@
 use crate::helpers::*;
 use anchor_lang::{AnchorDeserialize, AnchorSerialize};
 use solana_sdk::pubkey::Pubkey;
-use onchain_academy::state::Enrollment;
+use onchain_academy::state::{CompletionReceipt, Enrollment, EnrollmentV0};
 
 #[test]
 fn enrollment_size_constant_is_correct() {
-    // 8 (discriminator) + 32 (course) + 8 (enrolled_at) + (1 + 8) (completed_at Option<i64>)
-    // + 32 (lesson_flags [u64; 4]) + (1 + 32) (credential_asset Option<Pubkey>)
-    // + 4 (_reserved) + 1 (bump)
-    assert_eq!(Enrollment::SIZE, 8 + 32 + 8 + 9 + 32 + 33 + 4 + 1);
-    assert_eq!(Enrollment::SIZE, 127);
+    // 8 (discriminator) + 1 (schema_version) + 32 (course) + 8 (enrolled_at)
+    // + (1 + 8) (completed_at Option<i64>) + 32 (lesson_flags [u64; 4])
+    // + (1 + 32) (credential_asset Option<Pubkey>) + 32 (cohort) + 2 (attempt)
+    // + 8 (attempt_started_at) + 2 (course_version) + 20 (_reserved) + 1 (bump)
+    assert_eq!(Enrollment::SIZE, 8 + 1 + 32 + 8 + 9 + 32 + 33 + 32 + 2 + 8 + 2 + 20 + 1);
+    assert_eq!(Enrollment::SIZE, 188);
+    assert_eq!(Enrollment::LEGACY_SIZE, 127);
 }
 
 #[test]
 fn enrollment_serialization_roundtrip_in_progress() {
     let course = Pubkey::new_unique();
     let enrollment = Enrollment {
+        schema_version: 1,
         course,
         enrolled_at: 1700000000,
         completed_at: None,
         lesson_flags: [0u64; 4],
         credential_asset: None,
-        _reserved: [0u8; 4],
+        cohort: Pubkey::default(),
+        attempt: 0,
+        attempt_started_at: 0,
+        course_version: 0,
+        _reserved: [0u8; 20],
         bump: 252,
     };
 
@@ -29,12 +36,13 @@ fn enrollment_serialization_roundtrip_in_progress() {
     enrollment.serialize(&mut buf).unwrap();
     let deserialized = Enrollment::deserialize(&mut buf.as_slice()).unwrap();
 
+    assert_eq!(deserialized.schema_version, Enrollment::SCHEMA_VERSION);
     assert_eq!(deserialized.course, course);
     assert_eq!(deserialized.enrolled_at, 1700000000);
     assert_eq!(deserialized.completed_at, None);
     assert_eq!(deserialized.lesson_flags, [0u64; 4]);
     assert_eq!(deserialized.credential_asset, None);
-    assert_eq!(deserialized._reserved, [0u8; 4]);
+    assert_eq!(deserialized._reserved, [0u8; 20]);
     assert_eq!(deserialized.bump, 252);
 }
 
@@ -43,12 +51,17 @@ fn enrollment_serialization_roundtrip_completed() {
     let course = Pubkey::new_unique();
     let credential = Pubkey::new_unique();
     let enrollment = Enrollment {
+        schema_version: 1,
         course,
         enrolled_at: 1700000000,
         completed_at: Some(1700100000),
         lesson_flags: [0b1111, 0, 0, 0],
         credential_asset: Some(credential),
-        _reserved: [0u8; 4],
+        cohort: Pubkey::default(),
+        attempt: 0,
+        attempt_started_at: 0,
+        course_version: 0,
+        _reserved: [0u8; 20],
         bump: 250,
     };
 
@@ -65,12 +78,17 @@ fn enrollment_serialization_roundtrip_completed() {
 fn enrollment_serialized_size_matches_constant() {
     // All Option fields must be Some for worst-case size to match SIZE constant
     let enrollment = Enrollment {
+        schema_version: 1,
         course: Pubkey::new_unique(),
         enrolled_at: 0,
         completed_at: Some(12345),
         lesson_flags: [0u64; 4],
         credential_asset: Some(Pubkey::new_unique()),
-        _reserved: [0u8; 4],
+        cohort: Pubkey::default(),
+        attempt: 0,
+        attempt_started_at: 0,
+        course_version: 0,
+        _reserved: [0u8; 20],
         bump: 0,
     };
 
@@ -84,12 +102,17 @@ fn enrollment_serialized_size_matches_constant() {
 #[test]
 fn enrollment_serialized_size_none_options_fits_within_allocation() {
     let enrollment = Enrollment {
+        schema_version: 1,
         course: Pubkey::new_unique(),
         enrolled_at: 0,
         completed_at: None,
         lesson_flags: [0u64; 4],
         credential_asset: None,
-        _reserved: [0u8; 4],
+        cohort: Pubkey::default(),
+        attempt: 0,
+        attempt_started_at: 0,
+        course_version: 0,
+        _reserved: [0u8; 20],
         bump: 0,
     };
 
@@ -147,12 +170,17 @@ fn enrollment_pda_is_valid() {
 #[test]
 fn enrollment_bitmap_set_lesson() {
     let mut enrollment = Enrollment {
+        schema_version: 1,
         course: Pubkey::new_unique(),
         enrolled_at: 0,
         completed_at: None,
         lesson_flags: [0u64; 4],
         credential_asset: None,
-        _reserved: [0u8; 4],
+        cohort: Pubkey::default(),
+        attempt: 0,
+        attempt_started_at: 0,
+        course_version: 0,
+        _reserved: [0u8; 20],
         bump: 0,
     };
 
@@ -195,14 +223,123 @@ fn enrollment_completed_lessons_count() {
 #[test]
 fn enrollment_reserved_bytes_size() {
     let enrollment = Enrollment {
+        schema_version: 1,
         course: Pubkey::new_unique(),
         enrolled_at: 0,
         completed_at: None,
         lesson_flags: [0u64; 4],
         credential_asset: None,
-        _reserved: [0u8; 4],
+        cohort: Pubkey::default(),
+        attempt: 0,
+        attempt_started_at: 0,
+        course_version: 0,
+        _reserved: [0u8; 20],
         bump: 0,
     };
 
-    assert_eq!(enrollment._reserved.len(), 4);
+    assert_eq!(enrollment._reserved.len(), 20);
+}
+
+#[test]
+fn legacy_enrollment_layout_matches_legacy_size() {
+    let course = Pubkey::new_unique();
+    let legacy = EnrollmentV0 {
+        course,
+        enrolled_at: 1700000000,
+        completed_at: Some(1700100000),
+        lesson_flags: [0b111, 0, 0, 0],
+        credential_asset: Some(Pubkey::new_unique()),
+        _reserved: [0u8; 4],
+        bump: 249,
+    };
+
+    let mut buf = Vec::new();
+    legacy.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, Enrollment::LEGACY_SIZE);
+
+    // Unmigrated bytes must not pass as the current layout
+    assert!(Enrollment::deserialize(&mut buf.as_slice()).is_err());
+
+    let decoded = EnrollmentV0::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(decoded.course, course);
+    assert_eq!(decoded.lesson_flags[0], 0b111);
+    assert_eq!(decoded.bump, 249);
+}
+
+#[test]
+fn completion_receipt_size_constant_is_correct() {
+    // 8 (discriminator) + 1 (schema_version) + 32 (course) + 8 (completed_at)
+    // + (1 + 32) (credential_asset Option<Pubkey>) + 2 (course_version)
+    // + 8 (_reserved) + 1 (bump)
+    assert_eq!(CompletionReceipt::SIZE, 93);
+    assert!(CompletionReceipt::SIZE < Enrollment::SIZE);
+
+    let receipt = CompletionReceipt {
+        schema_version: 1,
+        course: Pubkey::new_unique(),
+        completed_at: 1700000000,
+        credential_asset: Some(Pubkey::new_unique()),
+        course_version: 3,
+        _reserved: [0u8; 8],
+        bump: 250,
+    };
+    let mut buf = Vec::new();
+    receipt.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, CompletionReceipt::SIZE);
+
+    let decoded = CompletionReceipt::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(decoded.completed_at, 1700000000);
+    assert_eq!(decoded.credential_asset, receipt.credential_asset);
+    assert_eq!(decoded.course_version, 3);
+}
+
+#[test]
+fn completion_receipt_pda_is_per_course_and_learner() {
+    let (course_a, _) = course_pda("course-a");
+    let (course_b, _) = course_pda("course-b");
+    let learner = Pubkey::new_unique();
+
+    let (receipt, _) = completion_receipt_pda(&course_a, &learner);
+    assert_ne!(receipt, completion_receipt_pda(&course_b, &learner).0);
+    assert_ne!(receipt, completion_receipt_pda(&course_a, &Pubkey::new_unique()).0);
+    // Distinct from the enrollment it replaces
+    assert_ne!(receipt, enrollment_pda("course-a", &learner).0);
+}
+
+fn enrollment_started(enrolled_at: i64, attempt_started_at: i64) -> Enrollment {
+    Enrollment {
+        schema_version: 1,
+        course: Pubkey::new_unique(),
+        enrolled_at,
+        completed_at: None,
+        lesson_flags: [0u64; 4],
+        credential_asset: None,
+        cohort: Pubkey::default(),
+        attempt: 0,
+        attempt_started_at,
+        course_version: 0,
+        _reserved: [0u8; 20],
+        bump: 0,
+    }
+}
+
+#[test]
+fn progress_never_expires_without_course_limit() {
+    let enrollment = enrollment_started(1000, 1000);
+    assert!(!enrollment.progress_expired(0, i64::MAX));
+}
+
+#[test]
+fn progress_expires_after_course_limit() {
+    let enrollment = enrollment_started(1000, 5000);
+    assert!(!enrollment.progress_expired(100, 5100));
+    assert!(enrollment.progress_expired(100, 5101));
+}
+
+#[test]
+fn progress_expiry_falls_back_to_enrolled_at() {
+    // Enrollments that predate attempt_started_at count from enrolled_at
+    let enrollment = enrollment_started(1000, 0);
+    assert!(!enrollment.progress_expired(100, 1100));
+    assert!(enrollment.progress_expired(100, 1101));
 }
@@ -1,22 +1,30 @@
 use crate::helpers::*;
 use anchor_lang::{AnchorDeserialize, AnchorSerialize};
 use solana_sdk::pubkey::Pubkey;
-use onchain_academy::state::Config;
+use onchain_academy::state::{Config, ConfigV0};
 
 #[test]
 fn config_size_constant_is_correct() {
-    // discriminator(8) + authority(32) + backend_signer(32) + xp_mint(32) + _reserved(8) + bump(1)
-    assert_eq!(Config::SIZE, 8 + 32 + 32 + 32 + 8 + 1);
-    assert_eq!(Config::SIZE, 113);
+    // discriminator(8) + schema_version(1) + authority(32) + backend_signer(32) + xp_mint(32)
+    // + daily_xp_cap(8) + pending_authority(32) + paused(1) + guardian(32)
+    // + _reserved(63) + bump(1)
+    assert_eq!(Config::SIZE, 8 + 1 + 32 + 32 + 32 + 8 + 32 + 1 + 32 + 63 + 1);
+    assert_eq!(Config::SIZE, 242);
+    assert_eq!(Config::LEGACY_SIZE, 113);
 }
 
 #[test]
 fn config_serialization_roundtrip() {
     let config = Config {
+        schema_version: 1,
         authority: Pubkey::new_unique(),
         backend_signer: Pubkey::new_unique(),
         xp_mint: Pubkey::new_unique(),
-        _reserved: [0u8; 8],
+        daily_xp_cap: 5_000,
+        pending_authority: Pubkey::new_unique(),
+        paused: Config::PAUSE_XP,
+        guardian: Pubkey::new_unique(),
+        _reserved: [0u8; 63],
         bump: 254,
     };
 
@@ -25,20 +33,54 @@ fn config_serialization_roundtrip() {
 
     let deserialized = Config::deserialize(&mut buf.as_slice()).unwrap();
 
+    assert_eq!(deserialized.schema_version, Config::SCHEMA_VERSION);
     assert_eq!(deserialized.authority, config.authority);
     assert_eq!(deserialized.backend_signer, config.backend_signer);
     assert_eq!(deserialized.xp_mint, config.xp_mint);
-    assert_eq!(deserialized._reserved, [0u8; 8]);
+    assert_eq!(deserialized.daily_xp_cap, 5_000);
+    assert_eq!(deserialized.pending_authority, config.pending_authority);
+    assert_eq!(deserialized.paused, Config::PAUSE_XP);
+    assert_eq!(deserialized.guardian, config.guardian);
     assert_eq!(deserialized.bump, 254);
 }
 
+#[test]
+fn new_config_fields_reuse_reserved_bytes() {
+    // A schema 1 Config written before pending_authority existed had 128
+    // zeroed reserved bytes; it must decode unpaused, with no guardian and
+    // no transfer pending.
+    let authority = Pubkey::new_unique();
+    let mut data = vec![Config::SCHEMA_VERSION];
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&[0u8; 128]);
+    data.push(253);
+    assert_eq!(data.len() + 8, Config::SIZE);
+
+    let config = Config::deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!(config.authority, authority);
+    assert_eq!(config.daily_xp_cap, 1_000);
+    assert_eq!(config.pending_authority, Pubkey::default());
+    assert_eq!(config.paused, 0);
+    assert_eq!(config.guardian, Pubkey::default());
+    assert_eq!(config._reserved, [0u8; 63]);
+    assert_eq!(config.bump, 253);
+}
+
 #[test]
 fn config_serialized_size_matches_constant() {
     let config = Config {
+        schema_version: 1,
         authority: Pubkey::new_unique(),
         backend_signer: Pubkey::new_unique(),
         xp_mint: Pubkey::new_unique(),
-        _reserved: [0u8; 8],
+        daily_xp_cap: u64::MAX,
+        pending_authority: Pubkey::new_unique(),
+        paused: Config::PAUSE_XP,
+        guardian: Pubkey::new_unique(),
+        _reserved: [0u8; 63],
         bump: 255,
     };
 
@@ -66,15 +108,82 @@ fn config_pda_is_valid() {
 }
 
 #[test]
-fn config_reserved_bytes_are_zeroed() {
-    let config = Config {
+fn legacy_config_decodes_as_v0() {
+    // Schema 0 Config: authority, backend_signer, xp_mint, 8 bytes that
+    // became daily_xp_cap, bump
+    let authority = Pubkey::new_unique();
+    let backend_signer = Pubkey::new_unique();
+    let xp_mint = Pubkey::new_unique();
+
+    let mut legacy = Vec::new();
+    legacy.extend_from_slice(authority.as_ref());
+    legacy.extend_from_slice(backend_signer.as_ref());
+    legacy.extend_from_slice(xp_mint.as_ref());
+    legacy.extend_from_slice(&[0u8; 8]);
+    legacy.push(254);
+    assert_eq!(legacy.len() + 8, Config::LEGACY_SIZE);
+
+    let config = ConfigV0::deserialize(&mut legacy.as_slice()).unwrap();
+    assert_eq!(config.authority, authority);
+    assert_eq!(config.backend_signer, backend_signer);
+    assert_eq!(config.xp_mint, xp_mint);
+    assert_eq!(config.daily_xp_cap, 0);
+    assert_eq!(config.bump, 254);
+
+    // Unmigrated bytes must not pass as the current layout
+    assert!(Config::deserialize(&mut legacy.as_slice()).is_err());
+}
+
+fn config_with_paused(paused: u8) -> Config {
+    Config {
+        schema_version: 1,
         authority: Pubkey::new_unique(),
         backend_signer: Pubkey::new_unique(),
         xp_mint: Pubkey::new_unique(),
-        _reserved: [0u8; 8],
-        bump: 1,
-    };
+        daily_xp_cap: 0,
+        pending_authority: Pubkey::default(),
+        paused,
+        guardian: Pubkey::default(),
+        _reserved: [0u8; 63],
+        bump: 255,
+    }
+}
+
+#[test]
+fn unpaused_config_allows_everything() {
+    let config = config_with_paused(0);
+    assert!(config.require_not_paused(Config::PAUSE_ALL).is_ok());
+    assert!(config.require_not_paused(Config::PAUSE_XP).is_ok());
+    assert!(config.require_not_paused(Config::PAUSE_CREDENTIALS).is_ok());
+    assert!(config.require_not_paused(Config::PAUSE_ACHIEVEMENTS).is_ok());
+}
 
-    assert_eq!(config._reserved, [0u8; 8]);
-    assert_eq!(config._reserved.len(), 8);
+#[test]
+fn global_pause_blocks_every_flag() {
+    let config = config_with_paused(Config::PAUSE_ALL);
+    assert!(config.require_not_paused(Config::PAUSE_ALL).is_err());
+    assert!(config.require_not_paused(Config::PAUSE_XP).is_err());
+    assert!(config.require_not_paused(Config::PAUSE_CREDENTIALS).is_err());
+    assert!(config.require_not_paused(Config::PAUSE_ACHIEVEMENTS).is_err());
+}
+
+#[test]
+fn scoped_pause_blocks_only_its_flag() {
+    let config = config_with_paused(Config::PAUSE_XP);
+    assert!(config.require_not_paused(Config::PAUSE_ALL).is_ok());
+    assert!(config.require_not_paused(Config::PAUSE_XP).is_err());
+    assert!(config.require_not_paused(Config::PAUSE_CREDENTIALS).is_ok());
+    // award_achievement with an XP reward checks both flags
+    assert!(config
+        .require_not_paused(Config::PAUSE_ACHIEVEMENTS | Config::PAUSE_XP)
+        .is_err());
+}
+
+#[test]
+fn pause_flags_are_distinct_bits() {
+    assert_eq!(Config::PAUSE_ALL, 0b0001);
+    assert_eq!(Config::PAUSE_XP, 0b0010);
+    assert_eq!(Config::PAUSE_CREDENTIALS, 0b0100);
+    assert_eq!(Config::PAUSE_ACHIEVEMENTS, 0b1000);
+    assert_eq!(Config::PAUSE_FLAGS, 0b1111);
 }
@@ -0,0 +1,213 @@
+use crate::helpers::*;
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use solana_sdk::pubkey::Pubkey;
+use onchain_academy::state::{LearnerProfile, SECONDS_PER_DAY};
+
+fn empty_profile() -> LearnerProfile {
+    LearnerProfile {
+        schema_version: 1,
+        learner: Pubkey::default(),
+        total_xp: 0,
+        courses_completed: 0,
+        current_streak: 0,
+        longest_streak: 0,
+        last_activity_at: 0,
+        daily_xp_day: 0,
+        daily_xp_earned: 0,
+        created_at: 0,
+        _reserved: [0u8; 64],
+        bump: 0,
+    }
+}
+
+#[test]
+fn learner_profile_size_constant_is_correct() {
+    // 8 (discriminator) + 1 (schema_version) + 32 (learner) + 8 (total_xp) + 4 (courses_completed)
+    // + 2 (current_streak) + 2 (longest_streak) + 8 (last_activity_at)
+    // + 8 (daily_xp_day) + 8 (daily_xp_earned)
+    // + 8 (created_at) + 64 (_reserved) + 1 (bump)
+    assert_eq!(LearnerProfile::SIZE, 154);
+}
+
+#[test]
+fn learner_profile_serialized_size_matches_constant() {
+    let mut profile = empty_profile();
+    profile.init_if_new(Pubkey::new_unique(), 1700000000, 254);
+
+    let mut buf = Vec::new();
+    profile.serialize(&mut buf).unwrap();
+
+    assert_eq!(buf.len() + 8, LearnerProfile::SIZE);
+}
+
+#[test]
+fn learner_profile_serialization_roundtrip() {
+    let learner = Pubkey::new_unique();
+    let mut profile = empty_profile();
+    profile.init_if_new(learner, 1700000000, 253);
+    profile.record_xp(1500, 1700000000, 0).unwrap();
+    profile.courses_completed = 2;
+    profile.record_activity(1700000000);
+
+    let mut buf = Vec::new();
+    profile.serialize(&mut buf).unwrap();
+    let deserialized = LearnerProfile::deserialize(&mut buf.as_slice()).unwrap();
+
+    assert_eq!(deserialized.schema_version, LearnerProfile::SCHEMA_VERSION);
+    assert_eq!(deserialized.learner, learner);
+    assert_eq!(deserialized.total_xp, 1500);
+    assert_eq!(deserialized.courses_completed, 2);
+    assert_eq!(deserialized.current_streak, 1);
+    assert_eq!(deserialized.longest_streak, 1);
+    assert_eq!(deserialized.last_activity_at, 1700000000);
+    assert_eq!(deserialized.daily_xp_day, 1700000000 / SECONDS_PER_DAY);
+    assert_eq!(deserialized.daily_xp_earned, 1500);
+    assert_eq!(deserialized.created_at, 1700000000);
+    assert_eq!(deserialized.bump, 253);
+}
+
+#[test]
+fn init_if_new_does_not_reset_existing_profile() {
+    let learner = Pubkey::new_unique();
+    let mut profile = empty_profile();
+    profile.init_if_new(learner, 100, 7);
+    profile.record_xp(42, 100, 0).unwrap();
+
+    // Second enroll hits init_if_needed on an existing account
+    profile.init_if_new(learner, 200, 7);
+
+    assert_eq!(profile.total_xp, 42);
+    assert_eq!(profile.created_at, 100);
+}
+
+#[test]
+fn record_xp_overflow_is_rejected() {
+    let mut profile = empty_profile();
+    profile.total_xp = u64::MAX;
+    assert!(profile.record_xp(1, 0, 0).is_err());
+    assert_eq!(profile.total_xp, u64::MAX);
+}
+
+#[test]
+fn daily_cap_allows_up_to_the_limit() {
+    let mut profile = empty_profile();
+    let day = 19_700 * SECONDS_PER_DAY;
+    profile.record_xp(600, day + 10, 1_000).unwrap();
+    profile.record_xp(400, day + 20, 1_000).unwrap();
+    assert_eq!(profile.daily_xp_earned, 1_000);
+    assert_eq!(profile.total_xp, 1_000);
+}
+
+#[test]
+fn daily_cap_rejects_excess() {
+    let mut profile = empty_profile();
+    let day = 19_700 * SECONDS_PER_DAY;
+    profile.record_xp(900, day, 1_000).unwrap();
+    assert!(profile.record_xp(101, day + 60, 1_000).is_err());
+}
+
+#[test]
+fn daily_cap_resets_on_next_day() {
+    let mut profile = empty_profile();
+    let day = 19_700 * SECONDS_PER_DAY;
+    profile.record_xp(1_000, day + SECONDS_PER_DAY - 1, 1_000).unwrap();
+    profile.record_xp(1_000, day + SECONDS_PER_DAY, 1_000).unwrap();
+    assert_eq!(profile.daily_xp_day, 19_701);
+    assert_eq!(profile.daily_xp_earned, 1_000);
+    assert_eq!(profile.total_xp, 2_000);
+}
+
+#[test]
+fn daily_cap_zero_means_unlimited() {
+    let mut profile = empty_profile();
+    profile.record_xp(u64::MAX / 2, 0, 0).unwrap();
+    profile.record_xp(u64::MAX / 2, 0, 0).unwrap();
+}
+
+#[test]
+fn backfill_counts_completed_legacy_enrollments() {
+    let mut profile = empty_profile();
+    profile.backfill_legacy(true, 0).unwrap();
+    profile.backfill_legacy(false, 0).unwrap();
+    profile.backfill_legacy(true, 0).unwrap();
+    assert_eq!(profile.courses_completed, 2);
+}
+
+#[test]
+fn backfill_raises_total_xp_to_the_token_balance() {
+    let mut profile = empty_profile();
+    profile.record_xp(300, 0, 0).unwrap();
+    profile.backfill_legacy(false, 1_200).unwrap();
+    assert_eq!(profile.total_xp, 1_200);
+    // A second migrated enrollment sees the same balance and adds nothing
+    profile.backfill_legacy(false, 1_200).unwrap();
+    assert_eq!(profile.total_xp, 1_200);
+    // Never lowers XP already recorded
+    profile.backfill_legacy(false, 0).unwrap();
+    assert_eq!(profile.total_xp, 1_200);
+    // Today's allowance is untouched
+    assert_eq!(profile.daily_xp_earned, 300);
+}
+
+#[test]
+fn streak_same_day_is_counted_once() {
+    let mut profile = empty_profile();
+    let day = 19_700 * SECONDS_PER_DAY;
+    profile.record_activity(day + 10);
+    profile.record_activity(day + 5_000);
+    assert_eq!(profile.current_streak, 1);
+    assert_eq!(profile.last_activity_at, day + 10);
+}
+
+#[test]
+fn streak_extends_on_consecutive_days() {
+    let mut profile = empty_profile();
+    let day = 19_700 * SECONDS_PER_DAY;
+    for i in 0..5 {
+        profile.record_activity(day + i * SECONDS_PER_DAY + 3_600);
+    }
+    assert_eq!(profile.current_streak, 5);
+    assert_eq!(profile.longest_streak, 5);
+}
+
+#[test]
+fn streak_resets_after_gap_but_keeps_longest() {
+    let mut profile = empty_profile();
+    let day = 19_700 * SECONDS_PER_DAY;
+    profile.record_activity(day);
+    profile.record_activity(day + SECONDS_PER_DAY);
+    profile.record_activity(day + 2 * SECONDS_PER_DAY);
+    // Skip a day
+    profile.record_activity(day + 4 * SECONDS_PER_DAY);
+    assert_eq!(profile.current_streak, 1);
+    assert_eq!(profile.longest_streak, 3);
+}
+
+#[test]
+fn streak_uses_calendar_days_not_elapsed_time() {
+    let mut profile = empty_profile();
+    let day = 19_700 * SECONDS_PER_DAY;
+    // 23:59 then 00:01 next day — two minutes apart but consecutive days
+    profile.record_activity(day + SECONDS_PER_DAY - 60);
+    profile.record_activity(day + SECONDS_PER_DAY + 60);
+    assert_eq!(profile.current_streak, 2);
+}
+
+#[test]
+fn learner_profile_pda_is_valid() {
+    let learner = Pubkey::new_unique();
+    let (pda, bump) = learner_profile_pda(&learner);
+    let derived = Pubkey::create_program_address(
+        &[b"learner", learner.as_ref(), &[bump]],
+        &PROGRAM_ID,
+    );
+    assert!(derived.is_ok());
+    assert_eq!(derived.unwrap(), pda);
+}
+
+#[test]
+fn different_learners_yield_different_profile_pdas() {
+    let (pda_a, _) = learner_profile_pda(&Pubkey::new_unique());
+    let (pda_b, _) = learner_profile_pda(&Pubkey::new_unique());
+    assert_ne!(pda_a, pda_b);
+}
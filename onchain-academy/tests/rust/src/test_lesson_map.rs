@@ -0,0 +1,112 @@
+use crate::helpers::*;
+use anchor_lang::AnchorSerialize;
+use solana_sdk::pubkey::Pubkey;
+use onchain_academy::state::{Enrollment, LessonMap};
+
+const REMOVED: u8 = LessonMap::REMOVED;
+
+fn lesson_map(from_version: u16, to_version: u16, mapping: Vec<u8>) -> LessonMap {
+    LessonMap {
+        schema_version: 1,
+        course: Pubkey::new_unique(),
+        from_version,
+        to_version,
+        new_lesson_count: 0,
+        mapping,
+        _reserved: [0u8; 16],
+        bump: 255,
+    }
+}
+
+fn flags(lessons: &[u8]) -> [u64; 4] {
+    let mut flags = [0u64; 4];
+    for &lesson in lessons {
+        flags[(lesson / 64) as usize] |= 1u64 << (lesson % 64);
+    }
+    flags
+}
+
+#[test]
+fn lesson_map_space_matches_serialized_size() {
+    for old_lesson_count in [1u8, 12, 255] {
+        let map = lesson_map(0, 2, vec![REMOVED; old_lesson_count as usize]);
+        let mut buf = Vec::new();
+        map.serialize(&mut buf).unwrap();
+        assert_eq!(buf.len() + 8, LessonMap::space(old_lesson_count));
+    }
+    // 8 + 1 + 32 + 2 + 2 + 1 + (4 + 255) + 16 + 1
+    assert_eq!(LessonMap::space(255), 322);
+}
+
+#[test]
+fn mapping_validation() {
+    // Reorder, append and remove
+    assert!(LessonMap::is_valid_mapping(&[2, 0, REMOVED, 1], 4));
+    assert!(LessonMap::is_valid_mapping(&[REMOVED, REMOVED], 1));
+    // Index outside the new layout
+    assert!(!LessonMap::is_valid_mapping(&[0, 3], 3));
+    // Two old lessons collapsed into one
+    assert!(!LessonMap::is_valid_mapping(&[1, 1], 2));
+    assert!(LessonMap::is_valid_mapping(&[254, 0], 255));
+}
+
+#[test]
+fn remap_moves_completed_lessons() {
+    // Old: 0 1 2 3 → new: lesson 1 removed, 3 moved to front, one appended at the end
+    let map = lesson_map(0, 2, vec![1, REMOVED, 2, 0]);
+    assert_eq!(map.remap(&flags(&[0, 1, 3])), flags(&[0, 1]));
+    assert_eq!(map.remap(&flags(&[])), flags(&[]));
+}
+
+#[test]
+fn remap_crosses_bitmap_words() {
+    let mut mapping = vec![REMOVED; 200];
+    mapping[5] = 130;
+    mapping[199] = 0;
+    let map = lesson_map(0, 2, mapping);
+    assert_eq!(map.remap(&flags(&[5, 199, 100])), flags(&[0, 130]));
+}
+
+#[test]
+fn map_applies_to_versions_since_previous_layout() {
+    // Layout last changed at v3; content-only bumps to v4 and v5; restructure → v6
+    let map = lesson_map(3, 6, vec![0]);
+    assert!(!map.applies_to(2));
+    assert!(map.applies_to(3));
+    assert!(map.applies_to(5));
+    assert!(!map.applies_to(6));
+
+    // Enrollments that predate the first restructure record version 0
+    assert!(lesson_map(0, 2, vec![0]).applies_to(0));
+}
+
+#[test]
+fn enrollment_needs_migration_only_before_layout_change() {
+    let mut enrollment = Enrollment {
+        schema_version: 1,
+        course: Pubkey::new_unique(),
+        enrolled_at: 0,
+        completed_at: None,
+        lesson_flags: [0u64; 4],
+        credential_asset: None,
+        cohort: Pubkey::default(),
+        attempt: 0,
+        attempt_started_at: 0,
+        course_version: 4,
+        _reserved: [0u8; 20],
+        bump: 0,
+    };
+    // Never restructured
+    assert!(!enrollment.needs_lesson_migration(0));
+    // Content-only bumps after the last restructure
+    assert!(!enrollment.needs_lesson_migration(3));
+    assert!(enrollment.needs_lesson_migration(6));
+    enrollment.course_version = 6;
+    assert!(!enrollment.needs_lesson_migration(6));
+}
+
+#[test]
+fn lesson_map_pda_is_per_version() {
+    let (course, _) = course_pda("restructured");
+    assert_ne!(lesson_map_pda(&course, 2).0, lesson_map_pda(&course, 3).0);
+}
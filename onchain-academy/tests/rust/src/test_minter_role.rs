@@ -1,14 +1,18 @@
 use crate::helpers::*;
 use anchor_lang::{AnchorDeserialize, AnchorSerialize};
 use solana_sdk::pubkey::Pubkey;
-use onchain_academy::state::{MinterRole, MAX_LABEL_LEN};
+use onchain_academy::state::{MinterRole, MinterRoleV0, MAX_LABEL_LEN};
 
 #[test]
 fn minter_role_size_constant_is_correct() {
-    // 8 (discriminator) + 32 (minter) + (4 + 32) (label)
+    // 8 (discriminator) + 1 (schema_version) + 32 (minter) + (4 + 32) (label)
     // + 8 (max_xp_per_call) + 8 (total_xp_minted)
-    // + 1 (is_active) + 8 (created_at) + 8 (_reserved) + 1 (bump)
-    assert_eq!(MinterRole::SIZE, 110);
+    // + 1 (is_active) + 8 (created_at)
+    // + 8 (window_seconds) + 8 (max_xp_per_window) + 8 (window_started_at)
+    // + 8 (window_xp_minted) + 8 (max_total_xp)
+    // + 32 (_reserved) + 1 (bump)
+    assert_eq!(MinterRole::SIZE, 175);
+    assert_eq!(MinterRole::LEGACY_SIZE, 110);
 }
 
 #[test]
@@ -19,13 +23,19 @@ fn max_label_len_is_32() {
 #[test]
 fn minter_role_serialization_roundtrip() {
     let minter_role = MinterRole {
+        schema_version: 1,
         minter: Pubkey::new_unique(),
         label: "streak-program".to_string(),
         max_xp_per_call: 500,
         total_xp_minted: 12000,
         is_active: true,
         created_at: 1700000000,
-        _reserved: [0u8; 8],
+        window_seconds: 86400,
+        max_xp_per_window: 10_000,
+        window_started_at: 1700000500,
+        window_xp_minted: 2_000,
+        max_total_xp: 1_000_000,
+        _reserved: [0u8; 32],
         bump: 253,
     };
 
@@ -40,20 +50,31 @@ fn minter_role_serialization_roundtrip() {
     assert_eq!(deserialized.total_xp_minted, 12000);
     assert!(deserialized.is_active);
     assert_eq!(deserialized.created_at, 1700000000);
-    assert_eq!(deserialized._reserved, [0u8; 8]);
+    assert_eq!(deserialized.window_seconds, 86400);
+    assert_eq!(deserialized.max_xp_per_window, 10_000);
+    assert_eq!(deserialized.window_started_at, 1700000500);
+    assert_eq!(deserialized.window_xp_minted, 2_000);
+    assert_eq!(deserialized.max_total_xp, 1_000_000);
+    assert_eq!(deserialized._reserved, [0u8; 32]);
     assert_eq!(deserialized.bump, 253);
 }
 
 #[test]
 fn minter_role_serialized_size_matches_constant() {
     let minter_role = MinterRole {
+        schema_version: 1,
         minter: Pubkey::new_unique(),
         label: "a".repeat(MAX_LABEL_LEN),
         max_xp_per_call: 0,
         total_xp_minted: 0,
         is_active: true,
         created_at: 0,
-        _reserved: [0u8; 8],
+        window_seconds: 0,
+        max_xp_per_window: 0,
+        window_started_at: 0,
+        window_xp_minted: 0,
+        max_total_xp: 0,
+        _reserved: [0u8; 32],
         bump: 0,
     };
 
@@ -67,13 +88,19 @@ fn minter_role_serialized_size_matches_constant() {
 #[test]
 fn minter_role_shorter_label_fits_within_allocation() {
     let minter_role = MinterRole {
+        schema_version: 1,
         minter: Pubkey::new_unique(),
         label: "short".to_string(),
         max_xp_per_call: 100,
         total_xp_minted: 0,
         is_active: true,
         created_at: 0,
-        _reserved: [0u8; 8],
+        window_seconds: 0,
+        max_xp_per_window: 0,
+        window_started_at: 0,
+        window_xp_minted: 0,
+        max_total_xp: 0,
+        _reserved: [0u8; 32],
         bump: 0,
     };
 
@@ -86,13 +113,19 @@ fn minter_role_shorter_label_fits_within_allocation() {
 #[test]
 fn minter_role_unlimited_xp_cap() {
     let minter_role = MinterRole {
+        schema_version: 1,
         minter: Pubkey::new_unique(),
         label: "unlimited".to_string(),
         max_xp_per_call: 0,
         total_xp_minted: 0,
         is_active: true,
         created_at: 0,
-        _reserved: [0u8; 8],
+        window_seconds: 0,
+        max_xp_per_window: 0,
+        window_started_at: 0,
+        window_xp_minted: 0,
+        max_total_xp: 0,
+        _reserved: [0u8; 32],
         bump: 1,
     };
 
@@ -103,18 +136,24 @@ fn minter_role_unlimited_xp_cap() {
 #[test]
 fn minter_role_reserved_bytes_are_zeroed() {
     let minter_role = MinterRole {
+        schema_version: 1,
         minter: Pubkey::new_unique(),
         label: "test".to_string(),
         max_xp_per_call: 0,
         total_xp_minted: 0,
         is_active: true,
         created_at: 0,
-        _reserved: [0u8; 8],
+        window_seconds: 0,
+        max_xp_per_window: 0,
+        window_started_at: 0,
+        window_xp_minted: 0,
+        max_total_xp: 0,
+        _reserved: [0u8; 32],
         bump: 1,
     };
 
-    assert_eq!(minter_role._reserved, [0u8; 8]);
-    assert_eq!(minter_role._reserved.len(), 8);
+    assert_eq!(minter_role._reserved, [0u8; 32]);
+    assert_eq!(minter_role._reserved.len(), 32);
 }
 
 #[test]
@@ -138,3 +177,87 @@ fn different_minters_yield_different_pdas() {
         Pubkey::find_program_address(&[b"minter", minter_b.as_ref()], &PROGRAM_ID);
     assert_ne!(pda_a, pda_b);
 }
+
+fn rate_limited_role(window_seconds: i64, max_xp_per_window: u64, max_total_xp: u64) -> MinterRole {
+    MinterRole {
+        schema_version: 1,
+        minter: Pubkey::new_unique(),
+        label: "events".to_string(),
+        max_xp_per_call: 0,
+        total_xp_minted: 0,
+        is_active: true,
+        created_at: 0,
+        window_seconds,
+        max_xp_per_window,
+        window_started_at: 0,
+        window_xp_minted: 0,
+        max_total_xp,
+        _reserved: [0u8; 32],
+        bump: 1,
+    }
+}
+
+#[test]
+fn window_budget_blocks_looped_calls() {
+    let mut role = rate_limited_role(86400, 10_000, 0);
+    let now = 1700000000;
+    for i in 0..10 {
+        role.record_mint(1_000, now + i).unwrap();
+    }
+    assert!(role.record_mint(1, now + 10).is_err());
+    assert_eq!(role.window_xp_minted, 10_000);
+    assert_eq!(role.total_xp_minted, 10_000);
+}
+
+#[test]
+fn window_budget_resets_after_window_elapses() {
+    let mut role = rate_limited_role(86400, 10_000, 0);
+    let now = 1700000000;
+    role.record_mint(10_000, now).unwrap();
+    assert!(role.record_mint(1, now + 86399).is_err());
+
+    role.record_mint(10_000, now + 86400).unwrap();
+    assert_eq!(role.window_started_at, now + 86400);
+    assert_eq!(role.window_xp_minted, 10_000);
+    assert_eq!(role.total_xp_minted, 20_000);
+}
+
+#[test]
+fn lifetime_cap_is_enforced_across_windows() {
+    let mut role = rate_limited_role(3600, 1_000, 1_500);
+    role.record_mint(1_000, 0).unwrap();
+    assert!(role.record_mint(600, 3600).is_err());
+    role.record_mint(500, 3600).unwrap();
+    assert_eq!(role.total_xp_minted, 1_500);
+}
+
+#[test]
+fn zero_limits_mean_unlimited() {
+    let mut role = rate_limited_role(0, 0, 0);
+    role.record_mint(u64::MAX / 2, 0).unwrap();
+    role.record_mint(u64::MAX / 2, 0).unwrap();
+}
+
+#[test]
+fn legacy_minter_role_layout_matches_legacy_size() {
+    let legacy = MinterRoleV0 {
+        minter: Pubkey::new_unique(),
+        label: "a".repeat(MAX_LABEL_LEN),
+        max_xp_per_call: 500,
+        total_xp_minted: 42,
+        is_active: true,
+        created_at: 1700000000,
+        _reserved: [0u8; 8],
+        bump: 250,
+    };
+
+    let mut buf = Vec::new();
+    legacy.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, MinterRole::LEGACY_SIZE);
+
+    // Legacy bytes must not decode as the new layout (migration is required)
+    assert!(MinterRole::deserialize(&mut buf.as_slice()).is_err());
+    let decoded = MinterRoleV0::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(decoded.total_xp_minted, 42);
+    assert_eq!(decoded.bump, 250);
+}
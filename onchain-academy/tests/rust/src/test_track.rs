@@ -0,0 +1,120 @@
+use crate::helpers::*;
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use solana_sdk::pubkey::Pubkey;
+use onchain_academy::state::{
+    Track, MAX_COURSES_PER_LEVEL, MAX_TRACK_LEVELS, MAX_TRACK_NAME_LEN, MAX_TRACK_URI_LEN,
+};
+
+fn track(max_level: u8, level_courses: Vec<Vec<Pubkey>>) -> Track {
+    Track {
+        schema_version: 1,
+        track_id: 3,
+        collection: Pubkey::new_unique(),
+        name: "Solana Security".to_string(),
+        uri_template: "https://arweave.net/security/{level}.json?owner={learner}".to_string(),
+        max_level,
+        level_courses,
+        created_at: 1700000000,
+        updated_at: 1700000000,
+        validity_period: 0,
+        recertification_course: Pubkey::default(),
+        _reserved: [0u8; 24],
+        bump: 251,
+    }
+}
+
+#[test]
+fn track_size_constant_is_correct() {
+    // 8 (discriminator) + 1 (schema_version) + 2 (track_id) + 32 (collection)
+    // + (4 + 32) (name) + (4 + 128) (uri_template) + 1 (max_level) + (4 + 8 * (4 + 4 * 32)) (level_courses)
+    // + 8 (created_at) + 8 (updated_at) + 8 (validity_period)
+    // + 32 (recertification_course) + 24 (_reserved) + 1 (bump)
+    assert_eq!(Track::SIZE, 1353);
+}
+
+#[test]
+fn track_serialized_size_matches_constant() {
+    let full = (0..MAX_TRACK_LEVELS)
+        .map(|_| (0..MAX_COURSES_PER_LEVEL).map(|_| Pubkey::new_unique()).collect())
+        .collect();
+    let mut registry = track(MAX_TRACK_LEVELS as u8, full);
+    registry.name = "a".repeat(MAX_TRACK_NAME_LEN);
+    registry.uri_template = "u".repeat(MAX_TRACK_URI_LEN);
+
+    let mut buf = Vec::new();
+    registry.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, Track::SIZE);
+
+    let deserialized = Track::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(deserialized.track_id, 3);
+    assert_eq!(deserialized.level_courses, registry.level_courses);
+    assert_eq!(deserialized.bump, 251);
+}
+
+#[test]
+fn levels_start_at_one() {
+    let registry = track(3, vec![]);
+    assert!(!registry.has_level(0));
+    assert!(registry.has_level(1));
+    assert!(registry.has_level(3));
+    assert!(!registry.has_level(4));
+}
+
+#[test]
+fn level_of_finds_listed_courses() {
+    let (intro, advanced, unlisted) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    // Level 2 left empty
+    let registry = track(3, vec![vec![intro], vec![], vec![advanced]]);
+    assert_eq!(registry.level_of(&intro), Some(1));
+    assert_eq!(registry.level_of(&advanced), Some(3));
+    assert_eq!(registry.level_of(&unlisted), None);
+}
+
+#[test]
+fn track_pda_is_per_track_id() {
+    assert_ne!(track_pda(1).0, track_pda(256).0);
+}
+
+#[test]
+fn credential_metadata_is_derived_from_track() {
+    let registry = track(3, vec![]);
+    let learner = Pubkey::new_unique();
+    assert_eq!(registry.credential_name(2), "Solana Security - Level 2");
+    assert_eq!(
+        registry.credential_uri(2, &learner),
+        format!("https://arweave.net/security/2.json?owner={}", learner)
+    );
+}
+
+#[test]
+fn uri_template_without_placeholders_is_used_verbatim() {
+    let mut registry = track(3, vec![]);
+    registry.uri_template = "https://arweave.net/security.json".to_string();
+    assert_eq!(
+        registry.credential_uri(3, &Pubkey::new_unique()),
+        "https://arweave.net/security.json"
+    );
+}
+
+#[test]
+fn credential_expiry_follows_validity_period() {
+    let mut registry = track(3, vec![]);
+    assert_eq!(registry.credential_expiry(1700000000), 0);
+    registry.validity_period = 365 * 86400;
+    assert_eq!(registry.credential_expiry(1700000000), 1731536000);
+}
+
+#[test]
+fn recertification_needs_period_and_course() {
+    let mut registry = track(3, vec![]);
+    registry.validity_period = 365 * 86400;
+    assert!(!registry.has_recertification());
+    registry.recertification_course = Pubkey::new_unique();
+    assert!(registry.has_recertification());
+    registry.validity_period = 0;
+    assert!(!registry.has_recertification());
+}
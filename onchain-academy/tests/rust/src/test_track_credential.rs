@@ -0,0 +1,115 @@
+use crate::helpers::*;
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use solana_sdk::pubkey::Pubkey;
+use onchain_academy::state::{CredentialStatus, TrackCredential};
+
+fn credential(asset: Pubkey) -> TrackCredential {
+    TrackCredential {
+        schema_version: 1,
+        learner: Pubkey::new_unique(),
+        track_id: 3,
+        asset,
+        level: 2,
+        courses_credited: 4,
+        issued_at: 1700000000,
+        updated_at: 1700086400,
+        revoked_at: 0,
+        expires_at: 0,
+        renewed_at: 0,
+        _reserved: [0u8; 40],
+        bump: 252,
+    }
+}
+
+#[test]
+fn track_credential_size_constant_is_correct() {
+    // 8 (discriminator) + 1 (schema_version) + 32 (learner) + 2 (track_id)
+    // + 32 (asset) + 1 (level) + 2 (courses_credited) + 8 (issued_at)
+    // + 8 (updated_at) + 8 (revoked_at) + 8 (expires_at) + 8 (renewed_at)
+    // + 40 (_reserved) + 1 (bump)
+    assert_eq!(TrackCredential::SIZE, 159);
+}
+
+#[test]
+fn track_credential_serialized_size_matches_constant() {
+    let record = credential(Pubkey::new_unique());
+
+    let mut buf = Vec::new();
+    record.serialize(&mut buf).unwrap();
+    assert_eq!(buf.len() + 8, TrackCredential::SIZE);
+
+    let deserialized = TrackCredential::deserialize(&mut buf.as_slice()).unwrap();
+    assert_eq!(deserialized.learner, record.learner);
+    assert_eq!(deserialized.asset, record.asset);
+    assert_eq!(deserialized.courses_credited, 4);
+    assert_eq!(deserialized.bump, 252);
+}
+
+#[test]
+fn freshly_allocated_credential_is_new() {
+    assert!(credential(Pubkey::default()).is_new());
+    assert!(!credential(Pubkey::new_unique()).is_new());
+}
+
+#[test]
+fn revocation_is_recorded_by_timestamp() {
+    let mut record = credential(Pubkey::new_unique());
+    assert!(!record.is_revoked());
+    record.revoked_at = 1700172800;
+    assert!(record.is_revoked());
+    assert!(!record.is_new());
+}
+
+#[test]
+fn credential_without_expiry_never_expires() {
+    let record = credential(Pubkey::new_unique());
+    assert!(!record.is_expired(i64::MAX));
+}
+
+#[test]
+fn credential_expires_at_its_expiry() {
+    let mut record = credential(Pubkey::new_unique());
+    record.expires_at = 1731536000;
+    assert!(!record.is_expired(1731535999));
+    assert!(record.is_expired(1731536000));
+}
+
+#[test]
+fn certified_at_tracks_latest_renewal() {
+    let mut record = credential(Pubkey::new_unique());
+    assert_eq!(record.certified_at(), 1700000000);
+    record.renewed_at = 1731536000;
+    assert_eq!(record.certified_at(), 1731536000);
+}
+
+#[test]
+fn status_reports_expiry_and_revocation() {
+    let mut record = credential(Pubkey::new_unique());
+    record.expires_at = 1731536000;
+    record.revoked_at = 1700172800;
+    assert_eq!(
+        record.status(1731536001),
+        CredentialStatus {
+            asset: record.asset,
+            track_id: 3,
+            level: 2,
+            issued_at: 1700000000,
+            expires_at: 1731536000,
+            revoked: true,
+            expired: true,
+        }
+    );
+}
+
+#[test]
+fn track_credential_pda_is_per_track_and_learner() {
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    assert_ne!(
+        track_credential_pda(1, &alice).0,
+        track_credential_pda(2, &alice).0
+    );
+    assert_ne!(
+        track_credential_pda(1, &alice).0,
+        track_credential_pda(1, &bob).0
+    );
+}
@@ -6,8 +6,11 @@ use libfuzzer_sys::fuzz_target;
 ///   enroll -> complete_lesson* -> finalize_course -> close_enrollment / issue_credential
 ///
 /// Tests state transition invariants without the Solana runtime.
-/// Reflects the simplified V1 program: no LearnerProfile, no daily XP cap,
-/// no streaks, no separate claim_completion_bonus (bonus merged into finalize).
+/// Models one learner on one course with a uniform `xp_per_lesson` and a flat
+/// `completion_bonus_xp`. Left out: the LearnerProfile (daily XP cap clamping,
+/// streaks, lifetime stats), per-lesson XP tables and `completion_bonus_bps`,
+/// prerequisites, schedules and cohorts, reset and re-enroll, pause flags,
+/// backend signer checks, and credentials beyond marking `credential_asset`.
 
 #[derive(Debug, Arbitrary, Clone)]
 enum Action {